@@ -0,0 +1,79 @@
+import { setDataDirOverride } from './lib/paths.js'
+import { auditEngineDependencies, getEngineStatus, planEngineSetup, runEngineSetup } from './ipc/engine.js'
+import { getLogger } from './lib/logger.js'
+
+const log = getLogger('electron.headless')
+
+type HeadlessCommand = 'setup' | 'status' | 'audit'
+
+export type HeadlessArgs = {
+  command: HeadlessCommand
+  dataDir: string | null
+  dryRun: boolean
+}
+
+const USAGE = 'Usage: biome --headless <setup [--dry-run]|status|audit> [--data-dir <path>]'
+
+/** Parse `--headless <command> [--dry-run] [--data-dir <path>]` from argv.
+ *  Returns null when `--headless` is absent so the normal windowed app
+ *  starts. */
+export function parseHeadlessArgs(argv: string[]): HeadlessArgs | null {
+  const index = argv.indexOf('--headless')
+  if (index === -1) return null
+
+  const command = argv[index + 1]
+  if (command !== 'setup' && command !== 'status' && command !== 'audit') {
+    throw new Error(USAGE)
+  }
+
+  let dataDir: string | null = null
+  const dataDirIndex = argv.indexOf('--data-dir')
+  if (dataDirIndex !== -1) {
+    dataDir = argv[dataDirIndex + 1] ?? null
+    if (!dataDir) throw new Error(USAGE)
+  }
+
+  const dryRun = argv.includes('--dry-run')
+  if (dryRun && command !== 'setup') throw new Error(USAGE)
+
+  return { command, dataDir, dryRun }
+}
+
+/** Run a headless command to completion and return the process exit code.
+ *  `status` prints the engine status as JSON on stdout; `setup` installs or
+ *  repairs uv, the server components and the venv, or with `--dry-run`
+ *  prints the plan as JSON and exits non-zero if it won't fit on disk.
+ *  `audit` prints the engine dependency audit as JSON. */
+export async function runHeadless(args: HeadlessArgs): Promise<number> {
+  if (args.dataDir) {
+    setDataDirOverride(args.dataDir)
+  }
+
+  try {
+    switch (args.command) {
+      case 'status': {
+        const status = await getEngineStatus('headless')
+        process.stdout.write(`${JSON.stringify(status, null, 2)}\n`)
+        return status.uv_installed && status.repo_cloned && status.dependencies_synced ? 0 : 1
+      }
+      case 'audit':
+        process.stdout.write(`${JSON.stringify(auditEngineDependencies(), null, 2)}\n`)
+        return 0
+      case 'setup':
+        if (args.dryRun) {
+          const plan = await planEngineSetup()
+          process.stdout.write(`${JSON.stringify(plan, null, 2)}\n`)
+          return plan.fits ? 0 : 1
+        }
+        await runEngineSetup()
+        log.info('Headless setup complete')
+        return 0
+    }
+  } catch (err) {
+    log.error('Headless command failed', {
+      fields: { command: args.command },
+      exception: err instanceof Error ? (err.stack ?? err.message) : String(err)
+    })
+    return 1
+  }
+}
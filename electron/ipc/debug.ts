@@ -1,9 +1,14 @@
-import { app, BrowserWindow, dialog, ipcMain } from 'electron'
+import { app, BrowserWindow, dialog, ipcMain, shell } from 'electron'
 import * as path from 'node:path'
 import * as fs from 'node:fs'
 import { execSync } from 'node:child_process'
 import os from 'node:os'
-import { getRecentElectronLogs } from '../lib/logger.js'
+import { getAppLogPath, getRecentElectronLogs, readRecentAppLogs } from '../lib/logger.js'
+import { listCrashReports } from '../lib/crashReports.js'
+
+/** Bounds for `read-recent-logs`; the line count comes from the renderer. */
+const READ_RECENT_LOGS_DEFAULT = 200
+const READ_RECENT_LOGS_MAX = 5000
 
 function resolveCommitHash(): string {
   const envCommit =
@@ -85,6 +90,25 @@ export function registerDebugIpc(): void {
   // (the structured Python events).
   ipcMain.handle('get-electron-log-tail', () => getRecentElectronLogs())
 
+  // Persistent counterpart of the rolling buffer above: reads back the
+  // rotated `biome.log`, so it also covers previous app runs.
+  ipcMain.handle('get-app-log-path', () => getAppLogPath())
+  ipcMain.handle('read-recent-logs', (_event, lines: unknown) => {
+    const requested = typeof lines === 'number' && Number.isFinite(lines) ? Math.floor(lines) : READ_RECENT_LOGS_DEFAULT
+    return readRecentAppLogs(Math.min(READ_RECENT_LOGS_MAX, Math.max(1, requested)))
+  })
+
+  // Crash reports from previous runs (JSON reports + native minidumps), so
+  // the renderer can offer to attach them to a GitHub issue on next launch.
+  ipcMain.handle('list-crash-reports', () => listCrashReports())
+
+  // Only paths `listCrashReports` itself returns can be revealed, so the
+  // renderer can't use this to probe arbitrary files.
+  ipcMain.handle('reveal-crash-report', (_event, reportPath: string) => {
+    if (!listCrashReports().some((entry) => entry.path === reportPath)) return
+    shell.showItemInFolder(reportPath)
+  })
+
   ipcMain.handle('export-loading-diagnostics', async (_event, reportText: string) => {
     const parentWindow = BrowserWindow.getFocusedWindow() || BrowserWindow.getAllWindows()[0]
     const timestamp = new Date().toISOString().replace(/[:.]/g, '-')
@@ -0,0 +1,160 @@
+import { app, ipcMain } from 'electron'
+import { execFile } from 'node:child_process'
+import fs from 'node:fs'
+import path from 'node:path'
+import { emitToAllWindows } from '../lib/ipcUtils.js'
+import { getLogger } from '../lib/logger.js'
+import { STANDALONE_PORT } from '../../src/types/settings.js'
+import type { DeepLink } from '../../src/types/ipc.js'
+
+const log = getLogger('electron.deep-link')
+
+const DEEP_LINK_SCHEME = 'biome'
+/** User-level desktop entry that makes Biome the `biome://` handler on
+ *  Linux, where the scheme isn't registered by the package. */
+const LINUX_HANDLER_DESKTOP_FILE = 'biome-url-handler.desktop'
+
+/** A link that arrived before the renderer was listening (cold start via
+ *  the link itself). Handed over once through `take-pending-deep-link`. */
+let pendingDeepLink: DeepLink | null = null
+
+function isTruthyFlag(value: string | null): boolean {
+  return ['1', 'true', 'yes'].includes((value ?? '').toLowerCase())
+}
+
+/** Parse a `biome://` URL into the action it asks for. Anything malformed
+ *  or unknown returns null — links come from chat messages, so the shape
+ *  is untrusted. Applying the action is left to the renderer, which asks
+ *  the user first.
+ *
+ *  - `biome://connect?host=1.2.3.4&port=8082&ssl=1` → server mode at that URL
+ *  - `biome://world?seed=<filename>` → launch with that seed */
+export function parseDeepLink(raw: string): DeepLink | null {
+  let url: URL
+  try {
+    url = new URL(raw)
+  } catch {
+    return null
+  }
+  if (url.protocol !== `${DEEP_LINK_SCHEME}:`) return null
+
+  // `biome://connect?...` puts the action in the host; tolerate the
+  // `biome:connect?...` form some chat clients rewrite links to.
+  const action = url.hostname || url.pathname.replace(/^\/+/, '')
+  const params = url.searchParams
+
+  switch (action) {
+    case 'connect': {
+      const host = params.get('host')?.trim()
+      if (!host || !/^[a-z0-9.\-:[\]]+$/i.test(host)) return null
+      const port = Number(params.get('port') ?? STANDALONE_PORT)
+      if (!Number.isInteger(port) || port < 1 || port > 65535) return null
+      const protocol = isTruthyFlag(params.get('ssl')) ? 'https' : 'http'
+      // IPv6 literals need brackets in a URL; accept them with or without.
+      const bare = host.replace(/^\[(.*)\]$/, '$1')
+      const serverUrl = `${protocol}://${bare.includes(':') ? `[${bare}]` : bare}:${port}`
+      if (!URL.canParse(serverUrl)) return null
+      return { kind: 'connect', server_url: serverUrl }
+    }
+    case 'world': {
+      const seed = params.get('seed')?.trim()
+      // Seeds are addressed by bare filename; refuse anything path-like.
+      if (!seed || path.basename(seed) !== seed || seed.startsWith('.')) return null
+      return { kind: 'world', seed }
+    }
+    default:
+      return null
+  }
+}
+
+/** Find a `biome://` URL in a process argv (Windows / Linux pass the link
+ *  as a command-line argument, both on cold start and via `second-instance`). */
+export function findDeepLinkArg(argv: string[]): string | null {
+  return argv.find((arg) => arg.startsWith(`${DEEP_LINK_SCHEME}:`)) ?? null
+}
+
+/** Park a link and nudge the renderer to collect it. Parking covers the
+ *  cold-start case where the window isn't listening yet. Also used for
+ *  imported world files, which go through the same confirm-then-launch flow. */
+export function queueDeepLink(link: DeepLink): void {
+  pendingDeepLink = link
+  emitToAllWindows('deep-link', link)
+}
+
+export function handleDeepLink(raw: string): void {
+  const link = parseDeepLink(raw)
+  if (!link) {
+    log.warning('Ignoring malformed deep link', { fields: { url: raw } })
+    return
+  }
+
+  log.info('Received deep link', { fields: { kind: link.kind } })
+  queueDeepLink(link)
+}
+
+/** Quote one `Exec=` argument per the desktop entry spec. */
+function quoteDesktopExecArg(arg: string): string {
+  return `"${arg.replace(/(["`$\\])/g, '\\$1')}"`
+}
+
+/** `setAsDefaultProtocolClient` on Linux only points xdg at an existing
+ *  desktop entry, and neither the AppImage nor a dev checkout installs one
+ *  with `MimeType=x-scheme-handler/biome`. Write our own into the user's
+ *  applications dir and make it the default handler. */
+function registerLinuxDeepLinkHandler(): void {
+  const command = process.env.APPIMAGE
+    ? [process.env.APPIMAGE]
+    : process.defaultApp && process.argv.length >= 2
+      ? [process.execPath, path.resolve(process.argv[1])]
+      : [process.execPath]
+  const dataHome = process.env.XDG_DATA_HOME || path.join(app.getPath('home'), '.local', 'share')
+  const desktopPath = path.join(dataHome, 'applications', LINUX_HANDLER_DESKTOP_FILE)
+  const entry = [
+    '[Desktop Entry]',
+    'Type=Application',
+    'Name=Biome',
+    `Exec=${command.map(quoteDesktopExecArg).join(' ')} %u`,
+    'NoDisplay=true',
+    `MimeType=x-scheme-handler/${DEEP_LINK_SCHEME};`,
+    ''
+  ].join('\n')
+
+  try {
+    fs.mkdirSync(path.dirname(desktopPath), { recursive: true })
+    fs.writeFileSync(desktopPath, entry)
+  } catch (err) {
+    log.warning('Could not write biome:// desktop entry', {
+      exception: err instanceof Error ? (err.stack ?? err.message) : String(err)
+    })
+    return
+  }
+  execFile('xdg-mime', ['default', LINUX_HANDLER_DESKTOP_FILE, `x-scheme-handler/${DEEP_LINK_SCHEME}`], (error) => {
+    if (error) log.warning('Could not register biome:// protocol handler', { fields: { error: error.message } })
+  })
+}
+
+/** Register Biome as the OS handler for `biome://`. In dev the app runs as
+ *  `electron .`, so the launcher has to pass the app path along. */
+export function registerDeepLinkProtocol(): void {
+  if (process.platform === 'linux') {
+    registerLinuxDeepLinkHandler()
+    return
+  }
+  const registered =
+    process.defaultApp && process.argv.length >= 2
+      ? app.setAsDefaultProtocolClient(DEEP_LINK_SCHEME, process.execPath, [path.resolve(process.argv[1])])
+      : app.setAsDefaultProtocolClient(DEEP_LINK_SCHEME)
+  if (!registered) {
+    log.warning('Could not register biome:// protocol handler')
+  }
+}
+
+export function registerDeepLinkIpc(): void {
+  // The renderer calls this on mount and again on every `deep-link`
+  // event, so a link is only ever acted on once.
+  ipcMain.handle('take-pending-deep-link', () => {
+    const link = pendingDeepLink
+    pendingDeepLink = null
+    return link
+  })
+}
@@ -1,17 +1,30 @@
-import { ipcMain } from 'electron'
+import { BrowserWindow, dialog, ipcMain, net } from 'electron'
 import fs from 'node:fs'
 import path from 'node:path'
 import { execFile } from 'node:child_process'
-import { getEngineDir, getUvDir } from '../lib/paths.js'
+import { INSTALLED_UV_LOCK, getEngineDir, getResourcePath, getUvDir } from '../lib/paths.js'
 import { getUvBinaryPath, getUvEnvVars } from '../lib/uv.js'
 import { getHiddenWindowOptions, getUvArchiveName, getVenvPythonPath } from '../lib/platform.js'
 import { getServerState, stopServerSync } from '../lib/serverState.js'
 import { runUvSyncWithMirroredLogs } from '../lib/uvSync.js'
 import { copyServerComponentFiles, ensureEngineFont } from '../lib/serverFiles.js'
+import { diffLockedPackages, isNonPypiSource, parseUvLock, wheelMatchesPlatform } from '../lib/uvLock.js'
 import { emitToAllWindows } from '../lib/ipcUtils.js'
 import { parseLogLine } from '../lib/logRecord.js'
 import { getLogger, recordElectronLog } from '../lib/logger.js'
-import { getOfflineEnv } from './settings.js'
+import { acquireInstallLock, releaseInstallLock } from '../lib/installLock.js'
+import { translate } from '../lib/i18n.js'
+import { notify } from './notifications.js'
+import { getNetworkEnv, getOfflineEnv, getUvReleasesBaseUrl, readSettingsSync } from './settings.js'
+import type {
+  EngineDependencyAudit,
+  EngineEnvReport,
+  EngineSetupPlan,
+  EngineSetupStep,
+  LockedPackage,
+  OfflineInstallResult
+} from '../../src/types/ipc.js'
+import type { EngineStatus } from '../../src/types/app.js'
 
 // `engine.setup` covers the user-visible phases (install uv, sync deps,
 // copy components, nuke); each call surfaces in the renderer's log buffer
@@ -20,9 +33,19 @@ import { getOfflineEnv } from './settings.js'
 const setupLog = getLogger('engine.setup', { defaultBroadcast: true })
 const diagLog = getLogger('engine.diagnostics')
 
+// The uv release we install, and the oldest one we support: anything
+// older is replaced on the next install / fix run.
 const UV_VERSION = '0.10.9'
 let engineInstallAbortController: AbortController | null = null
 
+/** Free space a fresh install needs: a managed Python, the CUDA torch
+ *  wheels in uv's cache, and the venv they're installed into. Deliberately
+ *  generous — running out mid-sync leaves a half-written venv and an
+ *  opaque IO error. A re-sync over an existing venv only fetches what
+ *  changed, so it gets the smaller figure. */
+const FRESH_INSTALL_REQUIRED_BYTES = 15 * 1024 ** 3
+const RESYNC_REQUIRED_BYTES = 2 * 1024 ** 3
+
 function execFileAsync(file: string, args: string[], options?: Parameters<typeof execFile>[2]): Promise<void> {
   return new Promise((resolve, reject) => {
     execFile(file, args, options ?? {}, (error) => {
@@ -35,6 +58,34 @@ function execFileAsync(file: string, args: string[], options?: Parameters<typeof
   })
 }
 
+/** Version reported by our uv binary (`uv 0.10.9 (abc123 2025-01-01)`),
+ *  or null when it's missing or won't run. The binary is the source of
+ *  truth, so there's no separate manifest to drift out of date. */
+async function getInstalledUvVersion(): Promise<string | null> {
+  const uvBinary = getUvBinaryPath()
+  if (!fs.existsSync(uvBinary)) return null
+  return new Promise((resolve) => {
+    execFile(uvBinary, ['--version'], { ...getHiddenWindowOptions() }, (error, stdout) => {
+      if (error) {
+        resolve(null)
+        return
+      }
+      resolve(/uv (\d+\.\d+\.\d+)/.exec(String(stdout))?.[1] ?? null)
+    })
+  })
+}
+
+/** True when dotted version `a` is lower than `b`. */
+function isOlderVersion(a: string, b: string): boolean {
+  const aParts = a.split('.').map(Number)
+  const bParts = b.split('.').map(Number)
+  for (let i = 0; i < Math.max(aParts.length, bParts.length); i++) {
+    const diff = (aParts[i] ?? 0) - (bParts[i] ?? 0)
+    if (diff !== 0) return diff < 0
+  }
+  return false
+}
+
 /** Unpack bundled server files to the engine directory */
 function unpackServerFilesInner(force: boolean): string {
   if (force) {
@@ -56,7 +107,7 @@ function unpackServerFilesInner(force: boolean): string {
 }
 
 /** Create .uv subdirectories, then run uv sync with mirrored logs. */
-async function syncEngineDependencies(signal?: AbortSignal): Promise<void> {
+async function syncEngineDependencies(signal?: AbortSignal, reinstall = false): Promise<void> {
   const engineDir = getEngineDir()
   const uvDir = getUvDir()
   const uvBinary = getUvBinaryPath()
@@ -74,13 +125,14 @@ async function syncEngineDependencies(signal?: AbortSignal): Promise<void> {
     fs.mkdirSync(path.join(uvDir, subdir), { recursive: true })
   }
 
-  setupLog.info('Running uv sync for engine dependencies')
+  setupLog.info('Running uv sync for engine dependencies', { fields: { reinstall } })
   await runUvSyncWithMirroredLogs(
     uvBinary,
     engineDir,
-    { ...process.env, ...uvEnv, ...getOfflineEnv() },
+    { ...process.env, ...uvEnv, ...getNetworkEnv(), ...getOfflineEnv() },
     {
       signal,
+      reinstall,
       onLine: (line, isStderr) => {
         // uv sync output exists nowhere else (Python's `server.log`
         // covers the Python server's own stdout, not uv's), so we
@@ -93,26 +145,301 @@ async function syncEngineDependencies(signal?: AbortSignal): Promise<void> {
     }
   )
   setupLog.info('uv sync finished for engine dependencies')
+
+  try {
+    fs.copyFileSync(path.join(engineDir, 'uv.lock'), path.join(engineDir, INSTALLED_UV_LOCK))
+  } catch (err) {
+    setupLog.warning('Could not record the installed uv.lock', {
+      exception: err instanceof Error ? (err.stack ?? err.message) : String(err)
+    })
+  }
 }
 
-/** Full engine setup: install UV if needed, copy server components, sync dependencies. */
-async function reinstallEngine(signal?: AbortSignal): Promise<void> {
-  setupLog.info('Checking uv installation')
+/** Modules the server imports at startup, checked by `verifyEngineEnv`.
+ *  Import names, not distribution names. A failure here means the server
+ *  can't boot. */
+const VERIFY_MODULES = [
+  'fastapi',
+  'uvicorn',
+  'pydantic',
+  'structlog',
+  'torch',
+  'numpy',
+  'PIL',
+  'timm',
+  'psutil',
+  'imageio_ffmpeg',
+  'huggingface_hub'
+]
+/** Modules loaded lazily or behind a soft-fail import. A failure is
+ *  reported as a warning only: the server still starts, and some are
+ *  expected to be missing on some machines. The legacy `world_engine`
+ *  package and the CUDA-only extras don't exist on macOS, where the server
+ *  runs `quark` alone. */
+const VERIFY_OPTIONAL_MODULES = ['quark', 'torchvision', 'websockets', 'simplejpeg', 'accelerate']
+const VERIFY_OPTIONAL_MODULES_NON_DARWIN = ['world_engine', 'pynvml', 'bitsandbytes', 'llama_cpp']
+
+/** Imports each module named in argv and prints the failures as one JSON
+ *  line. `BaseException` so a module that calls `sys.exit` on a broken
+ *  native library is reported rather than ending the check. */
+const VERIFY_SCRIPT = `
+import importlib, json, sys
+broken = []
+for name in sys.argv[1:]:
+    try:
+        importlib.import_module(name)
+    except BaseException as e:
+        broken.append({"module": name, "error": f"{type(e).__name__}: {e}"})
+print(json.dumps(broken))
+`
+
+/** Import every key module inside the synced venv and report which ones
+ *  fail, splitting required failures from optional ones. Runs with `UV_NO_SYNC` so it inspects the venv as it is rather
+ *  than quietly repairing it first. */
+async function verifyEngineEnv(): Promise<EngineEnvReport> {
+  const engineDir = getEngineDir()
   const uvBinary = getUvBinaryPath()
+  if (!fs.existsSync(path.join(engineDir, '.venv')) || !fs.existsSync(uvBinary)) {
+    throw new Error('Engine is not installed')
+  }
+
+  const optional =
+    process.platform === 'darwin'
+      ? VERIFY_OPTIONAL_MODULES
+      : [...VERIFY_OPTIONAL_MODULES, ...VERIFY_OPTIONAL_MODULES_NON_DARWIN]
+  const modules = [...VERIFY_MODULES, ...optional]
+  const stdout = await new Promise<string>((resolve, reject) => {
+    execFile(
+      uvBinary,
+      ['run', 'python', '-c', VERIFY_SCRIPT, ...modules],
+      {
+        cwd: engineDir,
+        env: { ...process.env, ...getUvEnvVars(), UV_NO_SYNC: '1' },
+        maxBuffer: 4 * 1024 * 1024,
+        ...getHiddenWindowOptions()
+      },
+      (error, out, stderr) => {
+        if (error) {
+          reject(new Error(`Could not run the engine's Python: ${String(stderr).trim() || error.message}`))
+          return
+        }
+        resolve(String(out))
+      }
+    )
+  })
 
-  let uvInstalled = false
-  if (fs.existsSync(uvBinary)) {
+  // Libraries may print banners on import; the report is the last line.
+  const lastLine = stdout.trim().split('\n').pop() ?? '[]'
+  const failures = JSON.parse(lastLine) as EngineEnvReport['broken']
+  const broken = failures.filter((f) => !optional.includes(f.module))
+  const warnings = failures.filter((f) => optional.includes(f.module))
+  if (broken.length > 0) {
+    setupLog.warning('Engine environment has broken modules', {
+      fields: { modules: broken.map((b) => b.module).join(', ') }
+    })
+  } else {
+    setupLog.info('Engine environment verified', { fields: { modules: modules.length } })
+  }
+  if (warnings.length > 0) {
+    setupLog.info('Optional engine modules failed to import', {
+      fields: { modules: warnings.map((w) => w.module).join(', ') }
+    })
+  }
+  return { ok: broken.length === 0, checked: modules, broken, warnings }
+}
+
+/** Walk up from `dir` to the closest directory that exists, so a
+ *  not-yet-created install dir can still be resolved to its volume. */
+function nearestExistingDir(dir: string): string {
+  let current = dir
+  while (!fs.existsSync(current)) {
+    const parent = path.dirname(current)
+    if (parent === current) break
+    current = parent
+  }
+  return current
+}
+
+const formatGb = (bytes: number): string => (bytes / 1024 ** 3).toFixed(1)
+
+function getRequiredInstallBytes(): number {
+  return fs.existsSync(path.join(getEngineDir(), '.venv')) ? RESYNC_REQUIRED_BYTES : FRESH_INSTALL_REQUIRED_BYTES
+}
+
+/** Free space on each volume the install writes to. The engine and uv dirs
+ *  normally share a volume, so it's only listed once; volumes that can't
+ *  be queried are logged and left out. */
+async function getInstallVolumes(): Promise<{ path: string; available: number }[]> {
+  const volumes: { path: string; available: number }[] = []
+  const seenDevices = new Set<number>()
+  for (const dir of [getEngineDir(), getUvDir()]) {
+    const existing = nearestExistingDir(dir)
     try {
-      await execFileAsync(uvBinary, ['--version'], { ...getHiddenWindowOptions() })
-      uvInstalled = true
-    } catch {
-      uvInstalled = false
+      const dev = fs.statSync(existing).dev
+      if (seenDevices.has(dev)) continue
+      seenDevices.add(dev)
+      const stats = await fs.promises.statfs(existing)
+      volumes.push({ path: existing, available: stats.bavail * stats.bsize })
+    } catch (err) {
+      setupLog.warning('Could not check free disk space, continuing', {
+        fields: { path: existing },
+        exception: err instanceof Error ? (err.stack ?? err.message) : String(err)
+      })
+    }
+  }
+  return volumes
+}
+
+/** Fail upfront with a readable "need X GB, have Y GB" instead of letting
+ *  uv run the disk full. If free space can't be queried the install goes
+ *  ahead rather than blocking on a failed probe. */
+async function ensureDiskSpaceForInstall(): Promise<void> {
+  const required = getRequiredInstallBytes()
+  for (const { path: existing, available } of await getInstallVolumes()) {
+    setupLog.info('Checked free disk space', {
+      fields: { path: existing, available_gb: formatGb(available), required_gb: formatGb(required) }
+    })
+    if (available < required) {
+      throw new Error(
+        `Not enough disk space on ${existing}: need ${formatGb(required)} GB, have ${formatGb(available)} GB`
+      )
     }
   }
+}
+
+/** Size of the uv release archive, from a HEAD request. Null when offline
+ *  or the server doesn't say. */
+async function getUvDownloadBytes(url: string): Promise<number | null> {
+  try {
+    const response = await net.fetch(url, { method: 'HEAD' })
+    const length = Number(response.headers.get('content-length'))
+    return response.ok && length > 0 ? length : null
+  } catch {
+    return null
+  }
+}
+
+function getBundledUvLockPath(): string {
+  return path.join(getResourcePath('server-components'), 'uv.lock')
+}
+
+/** Packages pinned in the bundled `uv.lock`. The lock covers every
+ *  platform, so this is an upper bound on what one sync installs. */
+function countLockedPackages(): number | null {
+  try {
+    return parseUvLock(fs.readFileSync(getBundledUvLockPath(), 'utf-8')).packages.length
+  } catch {
+    return null
+  }
+}
+
+/** Report on the bundled `uv.lock` (what the next setup or repair syncs)
+ *  against the lock recorded after the last successful sync. Read-only,
+ *  so it's safe to run before installing someone else's engine build. */
+export function auditEngineDependencies(): EngineDependencyAudit {
+  const lock = parseUvLock(fs.readFileSync(getBundledUvLockPath(), 'utf-8'))
+  const python = /(\d+)\.(\d+)/.exec(lock.requiresPython ?? '')?.slice(1).join('') ?? null
+
+  let wheelBytes = 0
+  const unsized: string[] = []
+  const sourceBuilds: string[] = []
+  for (const pkg of lock.packages) {
+    if (pkg.wheels.length === 0) {
+      // The engine project itself is local and never downloaded
+      if (pkg.source_kind !== 'editable' && pkg.source_kind !== 'virtual') sourceBuilds.push(pkg.name)
+      continue
+    }
+    // No wheel for this platform means the package (or this variant of
+    // it) is only installed elsewhere.
+    const wheel = pkg.wheels.find((w) => wheelMatchesPlatform(w.filename, python))
+    if (!wheel) continue
+    if (wheel.size === null) unsized.push(pkg.name)
+    else wheelBytes += wheel.size
+  }
+
+  const strip = ({ name, version, source_kind, source }: LockedPackage): LockedPackage => ({
+    name,
+    version,
+    source_kind,
+    source
+  })
+  const packages = lock.packages.map(strip)
+
+  let previous: LockedPackage[] | null = null
+  try {
+    previous = parseUvLock(fs.readFileSync(path.join(getEngineDir(), INSTALLED_UV_LOCK), 'utf-8')).packages.map(strip)
+  } catch {
+    // Never synced, or installed before the lock was recorded
+  }
+
+  return {
+    packages: packages.length,
+    wheel_bytes: wheelBytes,
+    unsized_wheels: unsized,
+    source_builds: sourceBuilds,
+    non_pypi: packages.filter(isNonPypiSource),
+    has_previous_lock: previous !== null,
+    ...(previous ? diffLockedPackages(previous, packages) : { added: [], removed: [], changed: [] })
+  }
+}
+
+/** What `runEngineSetup` would do right now, without doing any of it:
+ *  mirrors the decisions in `reinstallEngine`, reading state but never
+ *  writing to disk. Backs `plan-engine-setup` and `--headless setup
+ *  --dry-run`, so a confirmation screen can show the cost up front. */
+export async function planEngineSetup(): Promise<EngineSetupPlan> {
+  const steps: EngineSetupStep[] = []
+
+  const uvVersion = await getInstalledUvVersion()
+  if (!uvVersion || isOlderVersion(uvVersion, UV_VERSION)) {
+    const downloadUrl = `${getUvReleasesBaseUrl()}/${UV_VERSION}/${getUvArchiveName()}`
+    steps.push({
+      kind: 'install-uv',
+      version: UV_VERSION,
+      installed_version: uvVersion,
+      download_url: downloadUrl,
+      download_bytes: await getUvDownloadBytes(downloadUrl)
+    })
+  }
 
-  if (!uvInstalled) {
+  steps.push({ kind: 'copy-server-files', engine_dir: getEngineDir() })
+  steps.push({
+    kind: 'sync-packages',
+    fresh: !fs.existsSync(path.join(getEngineDir(), '.venv')),
+    locked_packages: countLockedPackages()
+  })
+
+  const requiredBytes = getRequiredInstallBytes()
+  const volumes = await getInstallVolumes()
+  return {
+    steps,
+    required_bytes: requiredBytes,
+    volumes: volumes.map(({ path: volumePath, available }) => ({ path: volumePath, available_bytes: available })),
+    fits: volumes.every(({ available }) => available >= requiredBytes)
+  }
+}
+
+/** Full engine setup: install UV if needed, copy server components, sync dependencies. */
+async function reinstallEngine(signal?: AbortSignal): Promise<void> {
+  await ensureDiskSpaceForInstall()
+
+  setupLog.info('Checking uv installation')
+  const uvVersion = await getInstalledUvVersion()
+
+  if (!uvVersion) {
     setupLog.info('Installing uv')
     await installUv()
+  } else if (isOlderVersion(uvVersion, UV_VERSION)) {
+    // Offline there's nothing to download from; an older uv still syncs
+    // from its cache, so keep it rather than failing setup.
+    if (readSettingsSync().offline_mode) {
+      setupLog.warning('Offline mode is on, keeping the installed uv', {
+        fields: { installed: uvVersion, wanted: UV_VERSION }
+      })
+    } else {
+      setupLog.info('Upgrading uv', { fields: { from: uvVersion, to: UV_VERSION } })
+      await installUv()
+    }
   }
 
   setupLog.info('Setting up server components')
@@ -121,6 +448,17 @@ async function reinstallEngine(signal?: AbortSignal): Promise<void> {
   setupLog.info('Syncing dependencies (this may take a while)')
   await syncEngineDependencies(signal)
 
+  // Plain `uv sync` trusts installed metadata, so a venv damaged on disk
+  // can survive it. Check afterwards and only log: forcing `--reinstall`
+  // is left to the explicit `repair-engine-env` action.
+  try {
+    await verifyEngineEnv()
+  } catch (err) {
+    setupLog.warning('Could not verify engine environment after sync', {
+      exception: err instanceof Error ? (err.stack ?? err.message) : String(err)
+    })
+  }
+
   setupLog.info('Setup complete')
 }
 
@@ -141,22 +479,68 @@ function nukeEngineDirectories(): void {
   }
 }
 
-async function installUv(): Promise<string> {
-  const uvDir = getUvDir()
-  const binDir = path.join(uvDir, 'bin')
-  fs.mkdirSync(binDir, { recursive: true })
-
+async function installUv(version = UV_VERSION): Promise<string> {
   const archiveName = getUvArchiveName()
-  const downloadUrl = `https://github.com/astral-sh/uv/releases/download/${UV_VERSION}/${archiveName}`
+  const downloadUrl = `${getUvReleasesBaseUrl()}/${version}/${archiveName}`
 
   setupLog.info('Downloading uv', { fields: { url: downloadUrl } })
-  const response = await fetch(downloadUrl)
+  // `net.fetch` rather than Node's fetch so the download goes through
+  // Chromium's network stack, which honours the system / configured proxy.
+  const response = await net.fetch(downloadUrl)
   if (!response.ok) {
     throw new Error(`Failed to download uv: HTTP ${response.status}`)
   }
 
   const arrayBuffer = await response.arrayBuffer()
-  const buffer = Buffer.from(arrayBuffer)
+  await extractUvArchive(Buffer.from(arrayBuffer), archiveName)
+
+  return `uv ${version} installed successfully`
+}
+
+/** Air-gapped counterpart of `installUv`: same extraction, but from an
+ *  archive the user downloaded elsewhere. It must be the release asset for
+ *  this platform (`getUvArchiveName`), since that decides both the format
+ *  and whether the binary inside will run here. */
+async function installUvFromArchive(archivePath: string): Promise<string> {
+  const expectedName = getUvArchiveName()
+  if (path.basename(archivePath) !== expectedName) {
+    throw new Error(
+      translate('app.settings.offlineInstall.wrongArchive', { expected: expectedName, got: path.basename(archivePath) })
+    )
+  }
+  if (!fs.existsSync(archivePath)) {
+    throw new Error(translate('app.settings.offlineInstall.notFound', { path: archivePath }))
+  }
+
+  setupLog.info('Installing uv from local archive', { fields: { path: archivePath } })
+  await extractUvArchive(await fs.promises.readFile(archivePath), expectedName)
+  return translate('app.settings.offlineInstall.uvInstalled', { version: (await getInstalledUvVersion()) ?? '?' })
+}
+
+/** Copy a pre-populated uv cache (e.g. `.uv/cache` from a machine that
+ *  completed an online install) into ours, so `uv sync` with offline mode
+ *  on can resolve every wheel locally. */
+function importUvCache(cacheDir: string): string {
+  // uv stamps every cache root with a CACHEDIR.TAG; its absence means the
+  // user picked the wrong directory (e.g. `.uv` instead of `.uv/cache`).
+  if (!fs.existsSync(path.join(cacheDir, 'CACHEDIR.TAG'))) {
+    throw new Error(translate('app.settings.offlineInstall.notACache', { path: cacheDir }))
+  }
+
+  const destDir = getUvEnvVars().UV_CACHE_DIR
+  fs.mkdirSync(destDir, { recursive: true })
+  setupLog.info('Importing uv cache', { fields: { from: cacheDir, to: destDir } })
+  fs.cpSync(cacheDir, destDir, { recursive: true, force: false, errorOnExist: false })
+  return translate('app.settings.offlineInstall.cacheImported')
+}
+
+/** Unpack a uv release archive into `.uv/bin`. Throws if the archive
+ *  didn't contain a uv binary, rather than leaving setup to fail later
+ *  with "uv is not installed". */
+async function extractUvArchive(buffer: Buffer, archiveName: string): Promise<void> {
+  const uvDir = getUvDir()
+  const binDir = path.join(uvDir, 'bin')
+  fs.mkdirSync(binDir, { recursive: true })
 
   if (archiveName.endsWith('.zip')) {
     // Windows: extract zip
@@ -164,8 +548,10 @@ async function installUv(): Promise<string> {
     const zip = new AdmZip(buffer)
     const entries = zip.getEntries()
 
+    // Entry names are only matched, never used as paths: the binary is
+    // written to a fixed destination.
     for (const entry of entries) {
-      if (entry.entryName.endsWith('uv.exe')) {
+      if (!entry.isDirectory && entry.entryName.endsWith('uv.exe')) {
         const destPath = path.join(binDir, 'uv.exe')
         fs.writeFileSync(destPath, entry.getData())
         break
@@ -180,7 +566,10 @@ async function installUv(): Promise<string> {
     await extract({
       file: tmpPath,
       cwd: uvDir,
-      filter: (entryPath) => {
+      // node-tar already strips `..` and absolute paths. Regular files only,
+      // so a symlink named `uv` can't point the copy below at another file.
+      filter: (entryPath, entry) => {
+        if ('type' in entry && entry.type !== 'File') return false
         return entryPath.endsWith('/uv') && !entryPath.endsWith('/uvx')
       }
     })
@@ -208,130 +597,263 @@ async function installUv(): Promise<string> {
     fs.rmSync(tmpPath, { force: true })
   }
 
-  return `uv ${UV_VERSION} installed successfully`
+  if (!fs.existsSync(getUvBinaryPath())) {
+    throw new Error(`${archiveName} did not contain a uv binary`)
+  }
 }
 
-export function registerEngineIpc(): void {
-  ipcMain.handle('check-engine-status', async (_event, source?: string) => {
-    const caller = source ?? 'unknown'
-    diagLog.info('check-engine-status: start', { fields: { caller } })
-    const engineDir = getEngineDir()
-    const uvBinary = getUvBinaryPath()
-    const uvEnv = getUvEnvVars()
-
-    // Check if our local uv binary exists and works
-    let uvInstalled = false
-    if (fs.existsSync(uvBinary)) {
+/** Snapshot of the local install (uv, server components, venv) and the
+ *  managed server process. Shared by the `check-engine-status` IPC and
+ *  the headless `status` command. */
+export async function getEngineStatus(caller: string): Promise<EngineStatus> {
+  diagLog.info('check-engine-status: start', { fields: { caller } })
+  const engineDir = getEngineDir()
+  const uvBinary = getUvBinaryPath()
+  const uvEnv = getUvEnvVars()
+
+  // Check if our local uv binary exists and works
+  diagLog.info('check-engine-status: validating uv binary')
+  const uvVersion = await getInstalledUvVersion()
+  const uvInstalled = uvVersion !== null
+  const uvOutdated = uvVersion !== null && isOlderVersion(uvVersion, UV_VERSION)
+  diagLog.info('check-engine-status: uv binary checked', {
+    fields: { version: uvVersion ?? 'none', outdated: uvOutdated }
+  })
+
+  // Check if server components are installed
+  const repoCloned =
+    fs.existsSync(engineDir) &&
+    fs.existsSync(path.join(engineDir, 'pyproject.toml')) &&
+    fs.existsSync(path.join(engineDir, 'main.py'))
+
+  // Check if dependencies are synced
+  let dependenciesSynced = false
+  if (repoCloned && fs.existsSync(path.join(engineDir, '.venv'))) {
+    const pythonPath = getVenvPythonPath(engineDir)
+    if (fs.existsSync(pythonPath)) {
       try {
-        diagLog.info('check-engine-status: validating uv binary')
-        await execFileAsync(uvBinary, ['--version'], {
+        diagLog.info('check-engine-status: validating synced dependencies via uv run python --version')
+        await execFileAsync(uvBinary, ['run', 'python', '--version'], {
+          cwd: engineDir,
+          env: { ...process.env, ...uvEnv, UV_FROZEN: '1' },
           ...getHiddenWindowOptions()
         })
-        uvInstalled = true
-        diagLog.info('check-engine-status: uv binary ok')
-      } catch {
-        uvInstalled = false
-        diagLog.info('check-engine-status: uv binary validation failed')
+        dependenciesSynced = true
+        diagLog.info('check-engine-status: dependency validation ok')
+      } catch (err) {
+        dependenciesSynced = false
+        const e = err as { stderr?: Buffer | string; stdout?: Buffer | string; message?: string; code?: number }
+        const stderr = e.stderr?.toString() ?? ''
+        const stdout = e.stdout?.toString() ?? ''
+        diagLog.info('check-engine-status: dependency validation failed', {
+          fields: {
+            exit_code: e.code ?? -1,
+            stderr: stderr.trim() || undefined,
+            stdout: stdout.trim() || undefined,
+            message: !stderr && !stdout ? (e.message ?? '') : undefined
+          }
+        })
       }
     }
+  }
 
-    // Check if server components are installed
-    const repoCloned =
-      fs.existsSync(engineDir) &&
-      fs.existsSync(path.join(engineDir, 'pyproject.toml')) &&
-      fs.existsSync(path.join(engineDir, 'main.py'))
-
-    // Check if dependencies are synced
-    let dependenciesSynced = false
-    if (repoCloned && fs.existsSync(path.join(engineDir, '.venv'))) {
-      const pythonPath = getVenvPythonPath(engineDir)
-      if (fs.existsSync(pythonPath)) {
-        try {
-          diagLog.info('check-engine-status: validating synced dependencies via uv run python --version')
-          await execFileAsync(uvBinary, ['run', 'python', '--version'], {
-            cwd: engineDir,
-            env: { ...process.env, ...uvEnv, UV_FROZEN: '1' },
-            ...getHiddenWindowOptions()
-          })
-          dependenciesSynced = true
-          diagLog.info('check-engine-status: dependency validation ok')
-        } catch (err) {
-          dependenciesSynced = false
-          const e = err as { stderr?: Buffer | string; stdout?: Buffer | string; message?: string; code?: number }
-          const stderr = e.stderr?.toString() ?? ''
-          const stdout = e.stdout?.toString() ?? ''
-          diagLog.info('check-engine-status: dependency validation failed', {
-            fields: {
-              exit_code: e.code ?? -1,
-              stderr: stderr.trim() || undefined,
-              stdout: stdout.trim() || undefined,
-              message: !stderr && !stdout ? (e.message ?? '') : undefined
-            }
-          })
-        }
-      }
+  // Check if server is running
+  const serverState = getServerState()
+  const serverRunning = serverState.process !== null
+  const serverPort = serverState.port
+
+  const serverLogPath = path.join(engineDir, 'server.log')
+
+  const result = {
+    uv_installed: uvInstalled,
+    uv_version: uvVersion,
+    uv_outdated: uvOutdated,
+    repo_cloned: repoCloned,
+    dependencies_synced: dependenciesSynced,
+    server_running: serverRunning,
+    server_port: serverPort,
+    server_log_path: serverLogPath
+  }
+  diagLog.info('check-engine-status: result', {
+    fields: {
+      uv_installed: result.uv_installed,
+      repo_cloned: result.repo_cloned,
+      dependencies_synced: result.dependencies_synced,
+      server_running: result.server_running
     }
+  })
+  return result
+}
+
+/** `uv cache prune`: drop cache entries no environment references any
+ *  more. Holds the install guard while it runs, so an install can't start
+ *  mid-prune. Returns false without running while an install or sync holds
+ *  the cache, or before uv is installed. */
+export async function pruneUvCache(): Promise<boolean> {
+  const uvBinary = getUvBinaryPath()
+  if (!fs.existsSync(uvBinary)) return false
+  try {
+    await withInstallGuard(() =>
+      execFileAsync(uvBinary, ['cache', 'prune'], {
+        env: { ...process.env, ...getUvEnvVars() },
+        ...getHiddenWindowOptions()
+      })
+    )
+  } catch (err) {
+    if (err instanceof InstallRunningError) return false
+    throw err
+  }
+  return true
+}
 
-    // Check if server is running
-    const serverState = getServerState()
-    const serverRunning = serverState.process !== null
-    const serverPort = serverState.port
+/** Thrown by `withInstallGuard` when the guard is already held, here or
+ *  by another Biome process. */
+class InstallRunningError extends Error {}
+
+/** Run `task` holding the install guard, so it can't overlap an install,
+ *  a `uv sync`, or anything else that writes uv's binary or cache. The
+ *  guard is the in-process controller plus the cross-process lockfile, so
+ *  a headless setup and the windowed app exclude each other too. The
+ *  guard's signal is what `abort-engine-install` aborts. */
+async function withInstallGuard<T>(task: (signal: AbortSignal) => Promise<T>): Promise<T> {
+  if (engineInstallAbortController) {
+    throw new InstallRunningError(translate('app.settings.engine.installRunning'))
+  }
+  if (!acquireInstallLock()) {
+    throw new InstallRunningError(translate('app.settings.engine.installRunningElsewhere'))
+  }
+  const controller = new AbortController()
+  engineInstallAbortController = controller
+  try {
+    return await task(controller.signal)
+  } finally {
+    engineInstallAbortController = null
+    releaseInstallLock()
+  }
+}
 
-    const serverLogPath = path.join(engineDir, 'server.log')
+/** Ask for a file or folder for one of the air-gapped setup steps. */
+async function pickOfflineInstallPath(options: Electron.OpenDialogOptions): Promise<string | null> {
+  const parentWindow = BrowserWindow.getFocusedWindow() || BrowserWindow.getAllWindows()[0]
+  const result = await dialog.showOpenDialog(parentWindow, options)
+  return result.canceled || result.filePaths.length === 0 ? null : result.filePaths[0]
+}
 
-    const result = {
-      uv_installed: uvInstalled,
-      repo_cloned: repoCloned,
-      dependencies_synced: dependenciesSynced,
-      server_running: serverRunning,
-      server_port: serverPort,
-      server_log_path: serverLogPath
-    }
-    diagLog.info('check-engine-status: result', {
-      fields: {
-        uv_installed: result.uv_installed,
-        repo_cloned: result.repo_cloned,
-        dependencies_synced: result.dependencies_synced,
-        server_running: result.server_running
-      }
+/** Run an air-gapped setup step under the install guard, reporting the
+ *  outcome as a translated message instead of a rejected invoke. */
+async function runOfflineInstallStep(step: () => Promise<string>): Promise<OfflineInstallResult> {
+  try {
+    return { ok: true, message: await withInstallGuard(step) }
+  } catch (err) {
+    setupLog.error('Offline install step failed', {
+      exception: err instanceof Error ? (err.stack ?? err.message) : String(err)
     })
-    return result
+    return { ok: false, message: err instanceof Error ? err.message : String(err) }
+  }
+}
+
+/** Install or repair the engine, single-flight. Backs `reinstall-engine`,
+ *  `nuke-and-reinstall-engine` and the headless `setup` command. `nuke`
+ *  wipes the engine and uv directories first, inside the guard. */
+export async function runEngineSetup({ nuke = false }: { nuke?: boolean } = {}): Promise<void> {
+  await withInstallGuard(async (signal) => {
+    if (nuke) nukeEngineDirectories()
+    try {
+      await reinstallEngine(signal)
+      notify('setup-complete')
+    } catch (err) {
+      // An abort is the user's own doing; only real failures are news.
+      if (!signal.aborted) {
+        notify('setup-failed', err instanceof Error ? err.message : String(err))
+      }
+      throw err
+    }
   })
+}
+
+export function registerEngineIpc(): void {
+  ipcMain.handle('check-engine-status', (_event, source?: string) => getEngineStatus(source ?? 'unknown'))
 
   ipcMain.handle('unpack-server-files', (_event, force: boolean) => {
     return unpackServerFilesInner(force)
   })
 
-  ipcMain.handle('reinstall-engine', async () => {
-    if (engineInstallAbortController) {
-      throw new Error('Engine install is already running')
-    }
+  ipcMain.handle('plan-engine-setup', () => planEngineSetup())
 
-    engineInstallAbortController = new AbortController()
-    try {
-      await reinstallEngine(engineInstallAbortController.signal)
-    } finally {
-      engineInstallAbortController = null
-    }
+  ipcMain.handle('audit-engine-dependencies', () => auditEngineDependencies())
 
+  ipcMain.handle('reinstall-engine', async () => {
+    await runEngineSetup()
     return 'Engine reinstalled successfully'
   })
 
   ipcMain.handle('nuke-and-reinstall-engine', async () => {
-    if (engineInstallAbortController) {
-      throw new Error('Engine install is already running')
-    }
+    await runEngineSetup({ nuke: true })
 
-    nukeEngineDirectories()
+    return 'Engine nuked and reinstalled successfully'
+  })
 
-    engineInstallAbortController = new AbortController()
-    try {
-      await reinstallEngine(engineInstallAbortController.signal)
-    } finally {
-      engineInstallAbortController = null
+  ipcMain.handle('get-uv-version', async () => ({
+    installed: await getInstalledUvVersion(),
+    supported: UV_VERSION
+  }))
+
+  // Install a specific uv release over the current one. Versions below
+  // the supported minimum are refused — the next setup run would only
+  // replace them again.
+  ipcMain.handle('upgrade-uv', async (_event, targetVersion?: string) => {
+    const version = targetVersion?.trim() || UV_VERSION
+    if (!/^\d+\.\d+\.\d+$/.test(version)) {
+      throw new Error(`Invalid uv version: ${version}`)
     }
+    if (isOlderVersion(version, UV_VERSION)) {
+      throw new Error(`uv ${version} is older than the minimum supported version ${UV_VERSION}`)
+    }
+    return withInstallGuard(async () => {
+      setupLog.info('Upgrading uv', { fields: { from: (await getInstalledUvVersion()) ?? 'none', to: version } })
+      return installUv(version)
+    })
+  })
 
-    return 'Engine nuked and reinstalled successfully'
+  ipcMain.handle('verify-engine-env', () => verifyEngineEnv())
+
+  // Forced `uv sync --reinstall` for a venv that verification flagged (or
+  // that the user suspects), without re-running the rest of setup.
+  ipcMain.handle('repair-engine-env', async () => {
+    await withInstallGuard(async (signal) => {
+      stopServerSync()
+      await syncEngineDependencies(signal, true)
+    })
+
+    return 'Engine environment repaired'
+  })
+
+  // Air-gapped setup: install uv and seed its package cache from local
+  // files instead of the network. Without a path, each asks with a file
+  // dialog and returns null when it's cancelled. Both hold the install
+  // guard for the whole copy so they can't race a running `uv sync`.
+  ipcMain.handle('install-uv-from-archive', async (_event, archivePath?: string) => {
+    const archiveName = getUvArchiveName()
+    const srcPath =
+      archivePath ??
+      (await pickOfflineInstallPath({
+        title: translate('app.settings.offlineInstall.uvArchiveDialogTitle'),
+        filters: [{ name: archiveName, extensions: [archiveName.endsWith('.zip') ? 'zip' : 'gz'] }],
+        properties: ['openFile']
+      }))
+    if (!srcPath) return null
+    return runOfflineInstallStep(() => installUvFromArchive(srcPath))
+  })
+
+  ipcMain.handle('import-uv-cache', async (_event, cacheDir?: string) => {
+    const srcDir =
+      cacheDir ??
+      (await pickOfflineInstallPath({
+        title: translate('app.settings.offlineInstall.uvCacheDialogTitle'),
+        properties: ['openDirectory']
+      }))
+    if (!srcDir) return null
+    return runOfflineInstallStep(async () => importUvCache(srcDir))
   })
 
   ipcMain.handle('abort-engine-install', () => {
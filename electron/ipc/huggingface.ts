@@ -0,0 +1,76 @@
+import { ipcMain, net } from 'electron'
+import { clearStoredHfToken, getStoredHfToken, storeHfToken } from '../lib/hfToken.js'
+import { getLogger } from '../lib/logger.js'
+import { translate } from '../lib/i18n.js'
+import { readSettingsSync } from './settings.js'
+import type { HfTokenCheckResult } from '../../src/types/ipc.js'
+
+const log = getLogger('electron.huggingface')
+
+const HF_DEFAULT_ENDPOINT = 'https://huggingface.co'
+const VALIDATE_TIMEOUT_MS = 10_000
+
+/** Ask the hub who the token belongs to. Goes through the configured HF
+ *  mirror when there is one, since that's where the server will send it.
+ *  Only a 401 counts as a verdict on the token; anything that stops us
+ *  asking leaves it `unverified`. */
+async function checkHfToken(token: string): Promise<HfTokenCheckResult> {
+  const settings = readSettingsSync()
+  if (settings.offline_mode) {
+    return { status: 'unverified', username: null, message: translate('app.settings.hfToken.unverifiedOffline') }
+  }
+  const endpoint = settings.network.hf_mirror.replace(/\/+$/, '') || HF_DEFAULT_ENDPOINT
+  try {
+    const response = await net.fetch(`${endpoint}/api/whoami-v2`, {
+      headers: { Authorization: `Bearer ${token}` },
+      signal: AbortSignal.timeout(VALIDATE_TIMEOUT_MS)
+    })
+    if (response.status === 401) {
+      return { status: 'rejected', username: null, message: translate('app.settings.hfToken.rejected') }
+    }
+    if (!response.ok) {
+      return {
+        status: 'unverified',
+        username: null,
+        message: translate('app.settings.hfToken.unverifiedHttp', { status: response.status })
+      }
+    }
+    const body = (await response.json()) as { name?: unknown }
+    return { status: 'valid', username: typeof body.name === 'string' ? body.name : null, message: null }
+  } catch (err) {
+    log.warning('Could not reach HuggingFace to check the token', {
+      exception: err instanceof Error ? err.message : String(err)
+    })
+    return { status: 'unverified', username: null, message: translate('app.settings.hfToken.unverifiedNetwork') }
+  }
+}
+
+export function registerHuggingFaceIpc(): void {
+  // Validates before storing so a typo never reaches the server spawn env.
+  // A token that couldn't be checked is stored anyway, since being offline
+  // says nothing about the token. The running server keeps the token it
+  // was spawned with; the settings UI offers the restart.
+  ipcMain.handle('set-hf-token', async (_event, token: string): Promise<HfTokenCheckResult> => {
+    const trimmed = token.trim()
+    const result = await checkHfToken(trimmed)
+    if (result.status !== 'rejected') {
+      storeHfToken(trimmed)
+      log.info('Stored HuggingFace token', {
+        fields: { username: result.username ?? 'unknown', status: result.status }
+      })
+    }
+    return result
+  })
+
+  ipcMain.handle('clear-hf-token', () => {
+    clearStoredHfToken()
+    log.info('Cleared stored HuggingFace token')
+  })
+
+  // Re-checks the stored token, so the settings UI can show whose it is
+  // (or that it has since been revoked).
+  ipcMain.handle('check-stored-hf-token', async (): Promise<HfTokenCheckResult | null> => {
+    const token = getStoredHfToken()
+    return token ? checkHfToken(token) : null
+  })
+}
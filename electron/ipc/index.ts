@@ -8,6 +8,14 @@ import { registerWindowIpc } from './window.js'
 import { registerDebugIpc } from './debug.js'
 import { registerUpdateIpc } from './update.js'
 import { registerRecordingsIpc } from './recordings.js'
+import { registerSessionsIpc } from './sessions.js'
+import { registerPromptHistoryIpc } from './promptHistory.js'
+import { registerScreenshotsIpc } from './screenshots.js'
+import { registerHuggingFaceIpc } from './huggingface.js'
+import { registerDeepLinkIpc } from './deepLink.js'
+import { registerTrayIpc } from './tray.js'
+import { registerNotificationsIpc } from './notifications.js'
+import { registerMaintenanceIpc } from './maintenance.js'
 
 export function registerAllIpc(): void {
   registerSettingsIpc()
@@ -20,4 +28,12 @@ export function registerAllIpc(): void {
   registerDebugIpc()
   registerUpdateIpc()
   registerRecordingsIpc()
+  registerSessionsIpc()
+  registerPromptHistoryIpc()
+  registerScreenshotsIpc()
+  registerHuggingFaceIpc()
+  registerDeepLinkIpc()
+  registerTrayIpc()
+  registerNotificationsIpc()
+  registerMaintenanceIpc()
 }
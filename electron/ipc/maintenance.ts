@@ -0,0 +1,197 @@
+import { ipcMain } from 'electron'
+import fs from 'node:fs'
+import path from 'node:path'
+import { getHfHubCacheDir, getUvDir } from '../lib/paths.js'
+import { getUvEnvVars } from '../lib/uv.js'
+import { listCrashReports } from '../lib/crashReports.js'
+import { getLogger } from '../lib/logger.js'
+import { pruneUvCache } from './engine.js'
+import { readSettingsSync } from './settings.js'
+import { refreshUpdateCheck } from './update.js'
+import type { MaintenanceJob, MaintenanceResult } from '../../src/types/ipc.js'
+import type { Settings } from '../../src/types/settings.js'
+
+const log = getLogger('electron.maintenance')
+
+/** First pass waits until startup (engine launch, initial update check)
+ *  has settled; after that a few passes a day is plenty. */
+const MAINTENANCE_INITIAL_DELAY_MS = 5 * 60 * 1000
+const MAINTENANCE_INTERVAL_MS = 6 * 60 * 60 * 1000
+
+const CRASH_REPORT_MAX_AGE_MS = 30 * 24 * 60 * 60 * 1000
+/** An in-progress download keeps touching its file, so anything this old
+ *  was abandoned (crash, kill, lost network). */
+const STALE_DOWNLOAD_MAX_AGE_MS = 24 * 60 * 60 * 1000
+
+/** How long to leave the uv cache alone after a prune that couldn't get it
+ *  under budget. What's left is referenced by the venv, so pruning again
+ *  won't help until something changes. */
+const UV_CACHE_OVER_BUDGET_BACKOFF_MS = 7 * 24 * 60 * 60 * 1000
+
+/** huggingface_hub writes blobs as `<hash>.incomplete` until they finish. */
+const HF_PARTIAL_DOWNLOAD_SUFFIX = '.incomplete'
+/** Release archive `extractUvArchive` stages in the uv dir. */
+const UV_PARTIAL_DOWNLOAD_NAME = 'uv-download.tar.gz'
+
+type JobOutcome = { status: 'ran' | 'skipped'; detail: string }
+
+type JobDefinition = {
+  job: MaintenanceJob
+  enabled: (settings: Settings) => boolean
+  run: (settings: Settings) => Promise<JobOutcome>
+}
+
+function formatGb(bytes: number): string {
+  return `${(bytes / 1024 ** 3).toFixed(1)} GB`
+}
+
+/** Regular files under `dir`, recursively. Symlinks are not followed. */
+async function* walkFiles(dir: string): AsyncGenerator<string> {
+  let entries: fs.Dirent[]
+  try {
+    entries = await fs.promises.readdir(dir, { withFileTypes: true })
+  } catch {
+    return
+  }
+  for (const entry of entries) {
+    const fullPath = path.join(dir, entry.name)
+    if (entry.isDirectory()) yield* walkFiles(fullPath)
+    else if (entry.isFile()) yield fullPath
+  }
+}
+
+async function dirSizeBytes(dir: string): Promise<number> {
+  let total = 0
+  for await (const filePath of walkFiles(dir)) {
+    try {
+      total += (await fs.promises.stat(filePath)).size
+    } catch {
+      // Removed mid-walk
+    }
+  }
+  return total
+}
+
+/** Set when a prune left the cache over budget. Cleared once the backoff
+ *  runs out or the budget changes, so a raised budget is checked right away. */
+let uvCacheBackoff: { budgetBytes: number; until: number } | null = null
+
+async function pruneUvCacheJob(settings: Settings): Promise<JobOutcome> {
+  const cacheDir = getUvEnvVars().UV_CACHE_DIR
+  const budgetBytes = settings.maintenance.uv_cache_budget_gb * 1024 ** 3
+  if (uvCacheBackoff && (uvCacheBackoff.budgetBytes !== budgetBytes || Date.now() >= uvCacheBackoff.until)) {
+    uvCacheBackoff = null
+  }
+  if (uvCacheBackoff) {
+    return { status: 'skipped', detail: 'still over budget after the last prune' }
+  }
+  const before = await dirSizeBytes(cacheDir)
+  if (before <= budgetBytes) {
+    return { status: 'skipped', detail: `${formatGb(before)} is within the ${formatGb(budgetBytes)} budget` }
+  }
+  if (!(await pruneUvCache())) {
+    return { status: 'skipped', detail: 'uv is busy or not installed' }
+  }
+  const after = await dirSizeBytes(cacheDir)
+  if (after > budgetBytes) {
+    log.warning('uv cache is still over budget after pruning, backing off', {
+      fields: { size: formatGb(after), budget: formatGb(budgetBytes) }
+    })
+    uvCacheBackoff = { budgetBytes, until: Date.now() + UV_CACHE_OVER_BUDGET_BACKOFF_MS }
+  }
+  return { status: 'ran', detail: `${formatGb(before)} → ${formatGb(after)}` }
+}
+
+async function pruneCrashReportsJob(): Promise<JobOutcome> {
+  const cutoff = Date.now() - CRASH_REPORT_MAX_AGE_MS
+  let removed = 0
+  for (const report of listCrashReports()) {
+    if (report.created_at_ms >= cutoff) continue
+    await fs.promises.rm(report.path, { force: true })
+    removed += 1
+  }
+  return { status: 'ran', detail: `removed ${removed} report(s)` }
+}
+
+async function cleanStaleDownloadsJob(): Promise<JobOutcome> {
+  const cutoff = Date.now() - STALE_DOWNLOAD_MAX_AGE_MS
+  const candidates = [path.join(getUvDir(), UV_PARTIAL_DOWNLOAD_NAME)]
+  for await (const filePath of walkFiles(getHfHubCacheDir())) {
+    if (filePath.endsWith(HF_PARTIAL_DOWNLOAD_SUFFIX)) candidates.push(filePath)
+  }
+
+  let removed = 0
+  for (const filePath of candidates) {
+    try {
+      if ((await fs.promises.stat(filePath)).mtimeMs >= cutoff) continue
+      await fs.promises.rm(filePath, { force: true })
+      removed += 1
+    } catch {
+      // Missing, or removed mid-walk
+    }
+  }
+  return { status: 'ran', detail: `removed ${removed} file(s)` }
+}
+
+async function updateCheckJob(): Promise<JobOutcome> {
+  const info = await refreshUpdateCheck()
+  return {
+    status: 'ran',
+    detail: info.update_available ? `${info.latest_version} available` : 'up to date'
+  }
+}
+
+const JOBS: JobDefinition[] = [
+  { job: 'uv-cache', enabled: (s) => s.maintenance.prune_uv_cache, run: pruneUvCacheJob },
+  { job: 'crash-reports', enabled: (s) => s.maintenance.prune_crash_reports, run: pruneCrashReportsJob },
+  { job: 'stale-downloads', enabled: (s) => s.maintenance.clean_stale_downloads, run: cleanStaleDownloadsJob },
+  { job: 'update-check', enabled: (s) => s.maintenance.check_for_updates, run: updateCheckJob }
+]
+
+/** The pass in flight, so the schedule and `run-maintenance-now` share it
+ *  rather than pruning the same directories twice at once. */
+let inFlight: Promise<MaintenanceResult[]> | null = null
+
+async function runJobs(): Promise<MaintenanceResult[]> {
+  const settings = readSettingsSync()
+  const results: MaintenanceResult[] = []
+  for (const { job, enabled, run } of JOBS) {
+    if (!enabled(settings)) {
+      results.push({ job, status: 'disabled', detail: '' })
+      continue
+    }
+    try {
+      results.push({ job, ...(await run(settings)) })
+    } catch (err) {
+      log.warning('Maintenance job failed', {
+        fields: { job },
+        exception: err instanceof Error ? (err.stack ?? err.message) : String(err)
+      })
+      results.push({ job, status: 'failed', detail: err instanceof Error ? err.message : String(err) })
+    }
+  }
+  log.info('Maintenance pass finished', {
+    fields: Object.fromEntries(results.map((r) => [r.job, r.detail ? `${r.status}: ${r.detail}` : r.status]))
+  })
+  return results
+}
+
+export function runMaintenance(): Promise<MaintenanceResult[]> {
+  inFlight ??= runJobs().finally(() => {
+    inFlight = null
+  })
+  return inFlight
+}
+
+/** Run a pass shortly after startup and then periodically. Timers are
+ *  unref'd so they never hold the process open on quit. */
+export function startMaintenanceSchedule(): void {
+  setTimeout(() => {
+    void runMaintenance()
+    setInterval(() => void runMaintenance(), MAINTENANCE_INTERVAL_MS).unref()
+  }, MAINTENANCE_INITIAL_DELAY_MS).unref()
+}
+
+export function registerMaintenanceIpc(): void {
+  ipcMain.handle('run-maintenance-now', () => runMaintenance())
+}
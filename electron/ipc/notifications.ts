@@ -0,0 +1,38 @@
+import { BrowserWindow, Notification, ipcMain } from 'electron'
+import { readSettingsSync } from './settings.js'
+import { translate, type MainTranslationKey } from '../lib/i18n.js'
+import type { AppNotificationKind } from '../../src/types/ipc.js'
+
+const NOTIFICATION_TITLES: Record<AppNotificationKind, MainTranslationKey> = {
+  'setup-complete': 'app.notifications.setupComplete',
+  'setup-failed': 'app.notifications.setupFailed',
+  'engine-crashed': 'app.notifications.engineCrashed',
+  'loading-failed': 'app.notifications.loadingFailed'
+}
+
+/** Post a native notification about a long-running operation. Skipped
+ *  when notifications are off, when there's no window (headless), or when
+ *  the window is focused — the in-app UI already shows the outcome. */
+export function notify(kind: AppNotificationKind, detail?: string): void {
+  if (!readSettingsSync().notifications.enabled || !Notification.isSupported()) return
+  const windows = BrowserWindow.getAllWindows()
+  if (windows.length === 0 || windows.some((win) => win.isFocused())) return
+
+  const notification = new Notification({ title: translate(NOTIFICATION_TITLES[kind]), body: detail ?? '' })
+  notification.on('click', () => {
+    const win = windows[0]
+    if (win.isMinimized()) win.restore()
+    win.show()
+    win.focus()
+  })
+  notification.show()
+}
+
+export function registerNotificationsIpc(): void {
+  // Outcomes only the renderer sees (world loading happens over the
+  // WebSocket), reported here so they go through the same gate.
+  ipcMain.handle('show-notification', (_event, kind: AppNotificationKind, detail?: string) => {
+    if (!(kind in NOTIFICATION_TITLES)) return
+    notify(kind, detail)
+  })
+}
@@ -0,0 +1,112 @@
+import { ipcMain } from 'electron'
+import fs from 'node:fs'
+import path from 'node:path'
+import { getConfigDir } from '../lib/paths.js'
+import { getLogger } from '../lib/logger.js'
+import type { PromptHistoryEntry } from '../../src/types/ipc.js'
+
+const log = getLogger('electron.prompt-history')
+
+const PROMPT_HISTORY_FILENAME = 'prompt_history.jsonl'
+
+/** Once the log holds this many lines it is compacted down to the unique
+ *  prompts on the next append, so repeated re-use of the same prompt
+ *  doesn't grow the file without bound. */
+const COMPACT_THRESHOLD_LINES = 2000
+const DEFAULT_SEARCH_LIMIT = 20
+
+function getPromptHistoryPath(): string {
+  return path.join(getConfigDir(), PROMPT_HISTORY_FILENAME)
+}
+
+/** Read every line of the append-only log. Lines that fail to parse
+ *  (e.g. a torn write on crash) are skipped individually. */
+function readRawEntries(): PromptHistoryEntry[] {
+  const historyPath = getPromptHistoryPath()
+  if (!fs.existsSync(historyPath)) return []
+
+  const entries: PromptHistoryEntry[] = []
+  for (const line of fs.readFileSync(historyPath, 'utf-8').split('\n')) {
+    if (!line.trim()) continue
+    try {
+      const obj = JSON.parse(line) as Partial<PromptHistoryEntry>
+      if (typeof obj.prompt === 'string' && typeof obj.used_at_ms === 'number') {
+        entries.push({ prompt: obj.prompt, used_at_ms: obj.used_at_ms })
+      }
+    } catch {
+      // Skip malformed line
+    }
+  }
+  return entries
+}
+
+/** Unique prompts, most recently used first. */
+function dedupeEntries(entries: PromptHistoryEntry[]): PromptHistoryEntry[] {
+  const latest = new Map<string, PromptHistoryEntry>()
+  for (const entry of entries) {
+    const prev = latest.get(entry.prompt)
+    if (!prev || entry.used_at_ms >= prev.used_at_ms) latest.set(entry.prompt, entry)
+  }
+  return [...latest.values()].sort((a, b) => b.used_at_ms - a.used_at_ms)
+}
+
+/** Subsequence match score: every query character must appear in order.
+ *  Consecutive and word-start hits score higher, so `snow for` ranks
+ *  "snowy forest" above "a snow-covered mountain fortress". Returns null
+ *  when the query doesn't match at all. */
+function fuzzyScore(text: string, query: string): number | null {
+  const haystack = text.toLowerCase()
+  const needle = query.toLowerCase()
+  let score = 0
+  let hIdx = 0
+  let prevMatch = -2
+  for (const ch of needle) {
+    const found = haystack.indexOf(ch, hIdx)
+    if (found === -1) return null
+    score += 1
+    if (found === prevMatch + 1) score += 2
+    if (found === 0 || /\s/.test(haystack[found - 1])) score += 3
+    prevMatch = found
+    hIdx = found + 1
+  }
+  // Prefer tighter matches when scores tie.
+  return score - haystack.length / 1000
+}
+
+export function registerPromptHistoryIpc(): void {
+  ipcMain.handle('add-prompt', (_event, prompt: string): void => {
+    const trimmed = prompt.trim()
+    if (!trimmed) return
+
+    fs.mkdirSync(getConfigDir(), { recursive: true })
+    const historyPath = getPromptHistoryPath()
+    const entry: PromptHistoryEntry = { prompt: trimmed, used_at_ms: Date.now() }
+    fs.appendFileSync(historyPath, JSON.stringify(entry) + '\n')
+
+    const entries = readRawEntries()
+    if (entries.length >= COMPACT_THRESHOLD_LINES) {
+      const compacted = dedupeEntries(entries).reverse()
+      fs.writeFileSync(historyPath, compacted.map((e) => JSON.stringify(e)).join('\n') + '\n')
+      log.info('Compacted prompt history', { fields: { before: entries.length, after: compacted.length } })
+    }
+  })
+
+  ipcMain.handle('search-prompts', (_event, query: string, limit?: number): PromptHistoryEntry[] => {
+    const max = Math.max(1, limit ?? DEFAULT_SEARCH_LIMIT)
+    const unique = dedupeEntries(readRawEntries())
+    const trimmed = query.trim()
+    if (!trimmed) return unique.slice(0, max)
+
+    return unique
+      .map((entry) => ({ entry, score: fuzzyScore(entry.prompt, trimmed) }))
+      .filter((r): r is { entry: PromptHistoryEntry; score: number } => r.score !== null)
+      .sort((a, b) => b.score - a.score || b.entry.used_at_ms - a.entry.used_at_ms)
+      .slice(0, max)
+      .map((r) => r.entry)
+  })
+
+  ipcMain.handle('clear-prompt-history', (): void => {
+    fs.rmSync(getPromptHistoryPath(), { force: true })
+    log.info('Cleared prompt history')
+  })
+}
@@ -4,6 +4,7 @@ import path from 'node:path'
 import open from 'open'
 import { parseFile } from 'music-metadata'
 import { getLogger } from '../lib/logger.js'
+import { isWithin } from '../lib/safePaths.js'
 import type { RecordingProperties } from '../../src/types/ipc.js'
 
 const log = getLogger('electron.recordings')
@@ -61,11 +62,6 @@ function ensureDir(dir: string): void {
   fs.mkdirSync(dir, { recursive: true })
 }
 
-function isWithin(child: string, parent: string): boolean {
-  const rel = path.relative(parent, child)
-  return rel !== '' && !rel.startsWith('..') && !path.isAbsolute(rel)
-}
-
 /** Launch the OS's default handler for `target` in a fully detached process.
  *  `open` takes care of the OS-native shell invocation, Windows path-quoting
  *  edge cases, and detaching + unref'ing so Biome can exit independently. */
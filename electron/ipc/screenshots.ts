@@ -0,0 +1,43 @@
+import { BrowserWindow, clipboard, ipcMain, shell } from 'electron'
+import fs from 'node:fs'
+import path from 'node:path'
+import { getScreenshotsDir } from '../lib/paths.js'
+import { getLogger } from '../lib/logger.js'
+import { emitToAllWindows } from '../lib/ipcUtils.js'
+import type { ScreenshotResult } from '../../src/types/ipc.js'
+
+const log = getLogger('electron.screenshots')
+
+export function registerScreenshotsIpc(): void {
+  // Grab the window contents (the stream canvas plus whatever overlay is
+  // up) via `capturePage`, which reads the compositor output — works the
+  // same whether the frame came from a <canvas> or a <video>.
+  ipcMain.handle('capture-screenshot', async (_event, copyToClipboard: boolean): Promise<ScreenshotResult> => {
+    const win = BrowserWindow.getFocusedWindow() || BrowserWindow.getAllWindows()[0]
+    if (!win) throw new Error('No window to capture')
+
+    const image = await win.webContents.capturePage()
+    if (image.isEmpty()) throw new Error('Captured frame is empty')
+
+    const dir = getScreenshotsDir()
+    fs.mkdirSync(dir, { recursive: true })
+    const ts = new Date().toISOString().replace(/[:.]/g, '-').replace('T', '_').slice(0, 23)
+    const filePath = path.join(dir, `biome_${ts}.png`)
+    await fs.promises.writeFile(filePath, image.toPNG())
+
+    if (copyToClipboard) {
+      clipboard.writeImage(image)
+    }
+
+    log.info('Saved screenshot', { fields: { path: filePath, clipboard: copyToClipboard } })
+    const result: ScreenshotResult = { path: filePath, copied_to_clipboard: copyToClipboard }
+    emitToAllWindows('screenshot-saved', result)
+    return result
+  })
+
+  ipcMain.handle('open-screenshots-folder', () => {
+    const dir = getScreenshotsDir()
+    fs.mkdirSync(dir, { recursive: true })
+    void shell.openPath(dir)
+  })
+}
@@ -1,9 +1,11 @@
 import { ipcMain, nativeImage, shell } from 'electron'
+import { randomUUID } from 'node:crypto'
 import fs from 'node:fs'
 import path from 'node:path'
 import { getSeedsDefaultDir, getSeedsGeneratedDir, getSeedsThumbnailDir, getSeedsUploadsDir } from '../lib/paths.js'
 import { SUPPORTED_IMAGE_EXTENSIONS } from '../lib/constants.js'
 import { getLogger } from '../lib/logger.js'
+import { resolveInside } from '../lib/safePaths.js'
 import type { SeedFileRecord, SeedSource } from '../../src/types/app.js'
 
 const log = getLogger('electron.seeds')
@@ -18,6 +20,8 @@ const IMAGE_EXTENSIONS: Record<string, string> = {
 }
 
 const SEED_THUMBNAIL_WIDTH_PX = 600
+/** Cap for `read-image-files`, which reads paths the renderer hands over. */
+const MAX_IMAGE_FILE_BYTES = 64 * 1024 * 1024
 
 function isSupportedImage(filename: string): boolean {
   const ext = path.extname(filename).slice(1).toLowerCase()
@@ -26,15 +30,20 @@ function isSupportedImage(filename: string): boolean {
 
 /** Find the full path of a seed by filename, searching default then uploads then generated dirs */
 function resolveSeedPath(filename: string): string | null {
-  const defaultPath = path.join(getSeedsDefaultDir(), filename)
-  if (fs.existsSync(defaultPath)) return defaultPath
-  const uploadsPath = path.join(getSeedsUploadsDir(), filename)
-  if (fs.existsSync(uploadsPath)) return uploadsPath
-  const generatedPath = path.join(getSeedsGeneratedDir(), filename)
-  if (fs.existsSync(generatedPath)) return generatedPath
+  for (const dir of [getSeedsDefaultDir(), getSeedsUploadsDir(), getSeedsGeneratedDir()]) {
+    const filePath = resolveInside(dir, filename)
+    if (filePath && fs.existsSync(filePath)) return filePath
+  }
   return null
 }
 
+/** Thumbnail cache path for a seed. Width-suffixed filename so any change
+ *  to the thumbnail resolution naturally invalidates the cache for
+ *  existing installs. */
+function getThumbnailPath(filename: string): string {
+  return path.join(getSeedsThumbnailDir(), `${path.parse(filename).name}.w${SEED_THUMBNAIL_WIDTH_PX}.jpg`)
+}
+
 function scanDir(dir: string, source: SeedSource): SeedFileRecord[] {
   if (!fs.existsSync(dir)) return []
   const records: SeedFileRecord[] = []
@@ -64,6 +73,37 @@ function dirForSource(source: SeedSource): string | null {
   }
 }
 
+/** Path of a seed in the directory for `source`, or null if it's gone. */
+export function findSeedFile(filename: string, source: SeedSource): string | null {
+  const dir = source === 'default' ? getSeedsDefaultDir() : dirForSource(source)
+  const filePath = dir ? resolveInside(dir, filename) : null
+  return filePath && fs.existsSync(filePath) ? filePath : null
+}
+
+/** Add an image to the uploads dir under `filename`, unless a different
+ *  image already has that name, in which case it gets a suffix. Returns the
+ *  filename it was stored under. */
+export function addUploadedSeed(filename: string, data: Buffer): string {
+  const name = path.basename(filename)
+  const uploadsDir = getSeedsUploadsDir()
+  if (!resolveInside(uploadsDir, name) || !isSupportedImage(name)) {
+    throw new Error(`${name} is not a supported image`)
+  }
+
+  fs.mkdirSync(uploadsDir, { recursive: true })
+
+  let storedName = name
+  const existingPath = path.join(uploadsDir, name)
+  if (fs.existsSync(existingPath)) {
+    if (fs.readFileSync(existingPath).equals(data)) return name
+    const ext = path.extname(name)
+    storedName = `${path.basename(name, ext)}_${randomUUID().slice(0, 8)}${ext}`
+  }
+
+  fs.writeFileSync(path.join(uploadsDir, storedName), data)
+  return storedName
+}
+
 export function registerSeedsIpc(): void {
   ipcMain.handle('list-seeds', (): SeedFileRecord[] => {
     const defaults = scanDir(getSeedsDefaultDir(), 'default')
@@ -83,11 +123,7 @@ export function registerSeedsIpc(): void {
     const filePath = resolveSeedPath(filename)
     if (!filePath) return null
 
-    const thumbDir = getSeedsThumbnailDir()
-    // Width-suffixed filename so any change to the thumbnail resolution
-    // naturally invalidates the cache for existing installs.
-    const thumbName = `${path.parse(filename).name}.w${SEED_THUMBNAIL_WIDTH_PX}.jpg`
-    const thumbPath = path.join(thumbDir, thumbName)
+    const thumbPath = getThumbnailPath(filePath)
 
     // Check if cached thumbnail exists and is newer than source
     if (fs.existsSync(thumbPath)) {
@@ -103,7 +139,7 @@ export function registerSeedsIpc(): void {
     }
 
     // Generate thumbnail via Electron nativeImage (aspect ratio preserved automatically)
-    fs.mkdirSync(thumbDir, { recursive: true })
+    fs.mkdirSync(getSeedsThumbnailDir(), { recursive: true })
     const img = nativeImage.createFromPath(filePath)
     if (img.isEmpty()) {
       log.error('Failed to load image for thumbnail', { fields: { path: filePath } })
@@ -118,8 +154,11 @@ export function registerSeedsIpc(): void {
 
   ipcMain.handle('upload-seed', (_event, filename: string, base64: string): SeedFileRecord => {
     const uploadsDir = getSeedsUploadsDir()
+    const destPath = resolveInside(uploadsDir, filename)
+    if (!destPath || !isSupportedImage(filename)) {
+      throw new Error(`${filename} is not a supported image`)
+    }
     fs.mkdirSync(uploadsDir, { recursive: true })
-    const destPath = path.join(uploadsDir, filename)
     fs.writeFileSync(destPath, Buffer.from(base64, 'base64'))
     const stat = fs.statSync(destPath)
     return { filename, source: 'uploaded', modifiedAt: stat.mtimeMs }
@@ -147,18 +186,12 @@ export function registerSeedsIpc(): void {
     // to delete from — filenames can collide across uploads/generated and we
     // would otherwise silently delete the wrong file.
     const dir = dirForSource(source)
-    if (!dir) return
-    const filePath = path.join(dir, filename)
-    if (!filePath.startsWith(dir)) return
-    if (!fs.existsSync(filePath)) return
+    const filePath = dir ? resolveInside(dir, filename) : null
+    if (!filePath || !fs.existsSync(filePath)) return
     fs.unlinkSync(filePath)
 
     // Also delete cached thumbnail
-    const thumbDir = getSeedsThumbnailDir()
-    // Width-suffixed filename so any change to the thumbnail resolution
-    // naturally invalidates the cache for existing installs.
-    const thumbName = `${path.parse(filename).name}.w${SEED_THUMBNAIL_WIDTH_PX}.jpg`
-    const thumbPath = path.join(thumbDir, thumbName)
+    const thumbPath = getThumbnailPath(filePath)
     if (fs.existsSync(thumbPath)) {
       try {
         fs.unlinkSync(thumbPath)
@@ -180,13 +213,19 @@ export function registerSeedsIpc(): void {
     shell.showItemInFolder(seedsDir)
   })
 
+  // Reads files the user copied to the clipboard, so the paths can be
+  // anywhere. Only absolute paths to regular files with an image extension
+  // and under the size cap are read.
   ipcMain.handle('read-image-files', (_event, paths: string[]) => {
     const results: { name: string; base64: string; mimeType: string }[] = []
     for (const filePath of paths) {
+      if (typeof filePath !== 'string' || !path.isAbsolute(filePath)) continue
       const ext = path.extname(filePath).toLowerCase()
       const mimeType = IMAGE_EXTENSIONS[ext]
       if (!mimeType) continue
       try {
+        const stat = fs.statSync(filePath)
+        if (!stat.isFile() || stat.size > MAX_IMAGE_FILE_BYTES) continue
         const data = fs.readFileSync(filePath)
         results.push({ name: path.basename(filePath), base64: data.toString('base64'), mimeType })
       } catch {
@@ -7,13 +7,24 @@ import path from 'node:path'
 import { getEngineDir, getHfHomeDir, getHfHubCacheDir } from '../lib/paths.js'
 import { getUvBinaryPath, getUvEnvVars, getBundledPythonIncludeDir } from '../lib/uv.js'
 import { getHiddenWindowOptions } from '../lib/platform.js'
-import { getServerState, setServerProcess, setServerReady, clearServerState, stopServer } from '../lib/serverState.js'
+import {
+  getServerState,
+  setServerProcess,
+  setServerReady,
+  clearServerState,
+  stopServer,
+  wasStoppedIntentionally
+} from '../lib/serverState.js'
 import { copyServerComponentFiles } from '../lib/serverFiles.js'
 import { parseLogLine } from '../lib/logRecord.js'
 import { getLogger } from '../lib/logger.js'
 import { emitToAllWindows } from '../lib/ipcUtils.js'
-import { getOfflineEnv } from './settings.js'
-import type { ServerHealthResult } from '../../src/types/ipc.js'
+import { translate } from '../lib/i18n.js'
+import { notify } from './notifications.js'
+import { getStoredHfToken } from '../lib/hfToken.js'
+import { getNetworkEnv, getOfflineEnv, readSettingsSync } from './settings.js'
+import type { LaunchPreview, ServerHealthResult } from '../../src/types/ipc.js'
+import { STANDALONE_PORT } from '../../src/types/settings.js'
 import { ServerCapabilitiesSchema } from '../../src/types/protocol.generated.js'
 
 const log = getLogger('engine.server')
@@ -29,12 +40,99 @@ let lastServerExitTail: string | null = null
 
 const LOG_TAIL_MAX_LINES = 40
 
+// Env vars the launch preview shows as `***`: the stored `HF_TOKEN` and
+// anything in `extra_env` that looks like a credential, since the preview
+// is meant to be copied into bug reports.
+const SECRET_ENV_KEY = /token|key|secret|passw(or)?d/i
+
+type ServerLaunch = {
+  command: string
+  args: string[]
+  cwd: string
+  env: Record<string, string>
+}
+
+/** The exact `spawn` inputs for the standalone server: Biome's own env
+ *  and flags plus the user's `engine_launch` tweaks (GPU pinning, extra
+ *  args / env). Shared by `start-engine-server` and `get-launch-preview`
+ *  so the preview can't drift from what actually runs. */
+function buildServerLaunch(port: number): ServerLaunch {
+  const engineDir = getEngineDir()
+  const uvEnv = getUvEnvVars()
+  const hfHomeDir = getHfHomeDir()
+  const hfHubCacheDir = getHfHubCacheDir()
+  const launchConfig = readSettingsSync().engine_launch
+
+  const serverEnv: Record<string, string> = {
+    ...(process.env as Record<string, string>),
+    ...uvEnv,
+    HF_HOME: hfHomeDir,
+    HF_HUB_CACHE: hfHubCacheDir,
+    HUGGINGFACE_HUB_CACHE: hfHubCacheDir,
+    HF_HUB_DOWNLOAD_TIMEOUT: '600',
+    PYTHONUNBUFFERED: '1',
+    PYTHONFAULTHANDLER: '1',
+    BIOME_SERVER_LOG_PATH: path.join(engineDir, 'server.log'),
+    // Pin the standalone-spawned server to JSON output regardless of what
+    // the parent shell has set.  In standalone mode the child's stdout is
+    // consumed by `parseLogLine` for the renderer's engine-log buffer; if
+    // the dev sets `BIOME_LOG_FORMAT=text` in their shell, the inherited
+    // value would degrade engineLogs to text-only fallback records.  The
+    // dev's terminal still gets the JSON via our raw pass-through write,
+    // so dropping into `jq` recovers the human-readable form when needed.
+    BIOME_LOG_FORMAT: 'json',
+    ...getNetworkEnv(),
+    ...getOfflineEnv(),
+    ...(launchConfig.gpu_device.trim() ? { CUDA_VISIBLE_DEVICES: launchConfig.gpu_device.trim() } : {}),
+    ...launchConfig.extra_env
+  }
+
+  // A token saved in settings fills in `HF_TOKEN` for gated models. An
+  // explicit `HF_TOKEN` in the launching shell still wins, matching the
+  // resolution order in `util/hf_token.py`.
+  const hfToken = getStoredHfToken()
+  if (hfToken && !serverEnv.HF_TOKEN) {
+    serverEnv.HF_TOKEN = hfToken
+  }
+
+  // Point the in-venv C compiler at the uv-managed Python headers so Triton's
+  // runtime JIT can #include <Python.h>. python-build-standalone's sysconfig
+  // reports an incorrect include path on NixOS; this override also helps
+  // users on distros where the system Python headers are absent.
+  const pythonIncludeDir = getBundledPythonIncludeDir()
+  if (pythonIncludeDir) {
+    const existingCPath = serverEnv.C_INCLUDE_PATH
+    serverEnv.C_INCLUDE_PATH = existingCPath ? `${pythonIncludeDir}:${existingCPath}` : pythonIncludeDir
+  }
+
+  // Base args for the server. Note that we use localhost for the host to prevent
+  // the Windows firewall for asking for permissions to expose the server to
+  // the world. `--launched-from-standalone` flags this process as belonging
+  // to a Biome standalone install — the renderer reads the bit back from
+  // /health and uses it to refuse an own-managed URL in server mode.
+  const baseServerArgs = [
+    'run',
+    'python',
+    '-u',
+    'main.py',
+    '--host',
+    '127.0.0.1',
+    '--port',
+    String(port),
+    '--launched-from-standalone'
+  ]
+
+  // Parent-process watchdog: the Python server force-exits if this Electron
+  // process disappears.
+  const serverArgs = [...baseServerArgs, '--parent-pid', String(process.pid), ...launchConfig.extra_args]
+
+  return { command: getUvBinaryPath(), args: serverArgs, cwd: engineDir, env: serverEnv }
+}
+
 export function registerServerIpc(): void {
   ipcMain.handle('start-engine-server', async (_event, port: number) => {
     const engineDir = getEngineDir()
     const uvBinary = getUvBinaryPath()
-    const uvEnv = getUvEnvVars()
-    const hfHomeDir = getHfHomeDir()
     const hfHubCacheDir = getHfHubCacheDir()
 
     // Check if server is already running
@@ -59,63 +157,12 @@ export function registerServerIpc(): void {
 
     log.info('Starting server', { fields: { port, engine_dir: engineDir, uv_binary: uvBinary } })
 
-    // Build env for server process
-    const serverEnv: Record<string, string> = {
-      ...(process.env as Record<string, string>),
-      ...uvEnv,
-      HF_HOME: hfHomeDir,
-      HF_HUB_CACHE: hfHubCacheDir,
-      HUGGINGFACE_HUB_CACHE: hfHubCacheDir,
-      HF_HUB_DOWNLOAD_TIMEOUT: '600',
-      PYTHONUNBUFFERED: '1',
-      PYTHONFAULTHANDLER: '1',
-      BIOME_SERVER_LOG_PATH: path.join(engineDir, 'server.log'),
-      // Pin the standalone-spawned server to JSON output regardless of what
-      // the parent shell has set.  In standalone mode the child's stdout is
-      // consumed by `parseLogLine` for the renderer's engine-log buffer; if
-      // the dev sets `BIOME_LOG_FORMAT=text` in their shell, the inherited
-      // value would degrade engineLogs to text-only fallback records.  The
-      // dev's terminal still gets the JSON via our raw pass-through write,
-      // so dropping into `jq` recovers the human-readable form when needed.
-      BIOME_LOG_FORMAT: 'json',
-      ...getOfflineEnv()
-    }
-
-    // Point the in-venv C compiler at the uv-managed Python headers so Triton's
-    // runtime JIT can #include <Python.h>. python-build-standalone's sysconfig
-    // reports an incorrect include path on NixOS; this override also helps
-    // users on distros where the system Python headers are absent.
-    const pythonIncludeDir = getBundledPythonIncludeDir()
-    if (pythonIncludeDir) {
-      const existingCPath = serverEnv.C_INCLUDE_PATH
-      serverEnv.C_INCLUDE_PATH = existingCPath ? `${pythonIncludeDir}:${existingCPath}` : pythonIncludeDir
-    }
-
-    // Base args for the server. Note that we use localhost for the host to prevent
-    // the Windows firewall for asking for permissions to expose the server to
-    // the world. `--launched-from-standalone` flags this process as belonging
-    // to a Biome standalone install — the renderer reads the bit back from
-    // /health and uses it to refuse an own-managed URL in server mode.
-    const baseServerArgs = [
-      'run',
-      'python',
-      '-u',
-      'main.py',
-      '--host',
-      '127.0.0.1',
-      '--port',
-      String(port),
-      '--launched-from-standalone'
-    ]
-
-    // Parent-process watchdog: the Python server force-exits if this Electron
-    // process disappears.
-    const serverArgs = [...baseServerArgs, '--parent-pid', String(process.pid)]
+    const launch = buildServerLaunch(port)
 
     // Spawn the server
-    const child = spawn(uvBinary, serverArgs, {
-      cwd: engineDir,
-      env: serverEnv,
+    const child = spawn(launch.command, launch.args, {
+      cwd: launch.cwd,
+      env: launch.env,
       stdio: ['ignore', 'pipe', 'pipe'],
       ...(process.platform !== 'win32' ? { detached: true } : {}), // Unix: new process group for clean kill
       ...getHiddenWindowOptions()
@@ -155,10 +202,26 @@ export function registerServerIpc(): void {
 
     child.on('exit', (code, signal) => {
       log.info('Server process exited', { fields: { code: code ?? -1, signal: signal ?? '' } })
-      if (code !== 0 && code !== null) {
+      // A non-zero exit code or a signal (the OOM killer's SIGKILL, a
+      // SIGSEGV in CUDA) from a process we didn't stop means the server
+      // died on its own. The exit status alone isn't enough: a Windows
+      // tree-kill exits with code 1. Push it so the renderer reconciles its
+      // lifecycle state (and auto-restarts) immediately rather than on the
+      // next status poll.
+      const crashed = signal !== null || (code !== 0 && code !== null)
+      if (crashed && !wasStoppedIntentionally(child)) {
         lastServerExitTail = recentLines.join('\n')
+        emitToAllWindows('engine-crashed', { exit_code: code, signal, log_tail: lastServerExitTail })
+        notify(
+          'engine-crashed',
+          signal !== null
+            ? translate('app.notifications.exitSignal', { signal })
+            : translate('app.notifications.exitCode', { code: code ?? -1 })
+        )
       }
-      clearServerState()
+      // A force-killed process can exit after its replacement has spawned;
+      // only clear the state if it still points at this one.
+      if (getServerState().process === child) clearServerState()
     })
 
     setServerProcess(child, port)
@@ -209,6 +272,19 @@ export function registerServerIpc(): void {
 
   ipcMain.handle('get-last-server-exit-tail', () => lastServerExitTail)
 
+  // What `start-engine-server` would run, for the launch-options preview.
+  // Only the variables Biome adds on top of its own environment are
+  // listed, with the HF token masked.
+  ipcMain.handle('get-launch-preview', (): LaunchPreview => {
+    const launch = buildServerLaunch(getServerState().port ?? STANDALONE_PORT)
+    const env: Record<string, string> = {}
+    for (const [key, value] of Object.entries(launch.env)) {
+      if (process.env[key] === value) continue
+      env[key] = SECRET_ENV_KEY.test(key) ? '***' : value
+    }
+    return { command: launch.command, args: launch.args, cwd: launch.cwd, env }
+  })
+
   ipcMain.handle('is-port-in-use', (_event, port: number) => {
     return new Promise<boolean>((resolve) => {
       const server = net.createServer()
@@ -0,0 +1,260 @@
+import { BrowserWindow, app, dialog, ipcMain } from 'electron'
+import { randomUUID } from 'node:crypto'
+import fs from 'node:fs'
+import path from 'node:path'
+import { getConfigDir } from '../lib/paths.js'
+import { getLogger } from '../lib/logger.js'
+import { translate } from '../lib/i18n.js'
+import { addUploadedSeed, findSeedFile } from './seeds.js'
+import { queueDeepLink } from './deepLink.js'
+import {
+  WORLD_FILE_FORMAT_VERSION,
+  sessionRecordSchema,
+  sessionStoreSchema,
+  worldFileManifestSchema
+} from '../../src/types/sessions.js'
+import type { SessionDraft, SessionRecord, WorldFileManifest } from '../../src/types/sessions.js'
+import type { ExportWorldFileResult, ImportWorldFileResult } from '../../src/types/ipc.js'
+
+const log = getLogger('electron.sessions')
+
+const SESSIONS_FILENAME = 'sessions.json'
+const SESSION_THUMBNAIL_DIR = 'session-thumbnails'
+
+const WORLD_FILE_EXTENSION = 'biomeworld'
+const WORLD_FILE_MANIFEST_ENTRY = 'manifest.json'
+const WORLD_FILE_SEED_DIR = 'seed'
+
+function getSessionsPath(): string {
+  return path.join(getConfigDir(), SESSIONS_FILENAME)
+}
+
+/** Read the session store. A missing file is an empty store; an unreadable
+ *  or invalid one is logged and treated as empty rather than failing the
+ *  IPC — the next save rewrites it in the current shape. */
+function readSessions(): SessionRecord[] {
+  const sessionsPath = getSessionsPath()
+  if (!fs.existsSync(sessionsPath)) return []
+
+  let parsed: unknown
+  try {
+    parsed = JSON.parse(fs.readFileSync(sessionsPath, 'utf-8'))
+  } catch (err) {
+    log.warning('Failed to parse sessions.json, ignoring', {
+      exception: err instanceof Error ? (err.stack ?? err.message) : String(err)
+    })
+    return []
+  }
+
+  const result = sessionStoreSchema.safeParse(parsed)
+  if (!result.success) {
+    log.warning('Invalid sessions.json, ignoring', { fields: { error: result.error.message } })
+    return []
+  }
+  return result.data.sessions
+}
+
+/** Thumbnails are named by session id, which is always one of ours (a UUID
+ *  or an id already in the store), so it is safe to use as a filename. */
+function getSessionThumbnailPath(id: string): string {
+  return path.join(getConfigDir(), SESSION_THUMBNAIL_DIR, `${path.basename(id)}.jpg`)
+}
+
+function writeSessions(sessions: SessionRecord[]): void {
+  fs.mkdirSync(getConfigDir(), { recursive: true })
+  // Write-then-rename so a crash mid-write can't truncate the store.
+  const sessionsPath = getSessionsPath()
+  const tmpPath = `${sessionsPath}.tmp`
+  fs.writeFileSync(tmpPath, JSON.stringify({ sessions }, null, 2))
+  fs.renameSync(tmpPath, sessionsPath)
+}
+
+/** The app version pins the engine version, and the engine only changes
+ *  behaviour across minor releases, so world files are compatible within one. */
+function isCompatibleVersion(fileVersion: string): boolean {
+  const minor = (version: string) => version.split('.').slice(0, 2).join('.')
+  return minor(fileVersion) === minor(app.getVersion())
+}
+
+async function exportWorldFile(record: SessionRecord, destPath: string): Promise<void> {
+  const seedPath = findSeedFile(record.seed_filename, record.seed_source)
+  if (!seedPath) throw new Error(translate('app.dialogs.savedWorlds.seedMissing'))
+
+  const { id: _id, server_url: _serverUrl, created_at_ms: _created, updated_at_ms: _updated, ...session } = record
+  const manifest: WorldFileManifest = { format_version: WORLD_FILE_FORMAT_VERSION, session }
+
+  const AdmZip = (await import('adm-zip')).default
+  const zip = new AdmZip()
+  zip.addFile(WORLD_FILE_MANIFEST_ENTRY, Buffer.from(JSON.stringify(manifest, null, 2)))
+  zip.addFile(`${WORLD_FILE_SEED_DIR}/${path.basename(seedPath)}`, await fs.promises.readFile(seedPath))
+  await zip.writeZipPromise(destPath)
+}
+
+/** Validate a `.biomeworld` file and save it as a new session. Archive
+ *  entry names are never used as paths: only the manifest and the one seed
+ *  entry it names are read, and the seed goes through `addUploadedSeed`. */
+async function importWorldFile(srcPath: string): Promise<SessionRecord> {
+  const file = path.basename(srcPath)
+  const AdmZip = (await import('adm-zip')).default
+  let zip: InstanceType<typeof AdmZip>
+  try {
+    zip = new AdmZip(srcPath)
+  } catch {
+    throw new Error(translate('app.dialogs.savedWorlds.notWorldFile', { file }))
+  }
+
+  const manifestEntry = zip.getEntry(WORLD_FILE_MANIFEST_ENTRY)
+  let parsed: unknown
+  try {
+    parsed = manifestEntry ? JSON.parse(manifestEntry.getData().toString('utf-8')) : null
+  } catch {
+    parsed = null
+  }
+  const result = worldFileManifestSchema.safeParse(parsed)
+  if (!result.success) throw new Error(translate('app.dialogs.savedWorlds.notWorldFile', { file }))
+
+  const { session } = result.data
+  if (!isCompatibleVersion(session.app_version)) {
+    throw new Error(
+      translate('app.dialogs.savedWorlds.versionMismatch', {
+        file,
+        fileVersion: session.app_version,
+        appVersion: app.getVersion()
+      })
+    )
+  }
+
+  const seedEntry = zip.getEntry(`${WORLD_FILE_SEED_DIR}/${path.basename(session.seed_filename)}`)
+  if (!seedEntry) throw new Error(translate('app.dialogs.savedWorlds.worldFileSeedMissing', { file }))
+  const seedFilename = addUploadedSeed(session.seed_filename, seedEntry.getData())
+
+  const now = Date.now()
+  const record = sessionRecordSchema.parse({
+    ...session,
+    id: randomUUID(),
+    seed_filename: seedFilename,
+    seed_source: 'uploaded',
+    created_at_ms: now,
+    updated_at_ms: now
+  })
+  writeSessions([record, ...readSessions()])
+  return record
+}
+
+export function registerSessionsIpc(): void {
+  ipcMain.handle('save-session', (_event, draft: SessionDraft, thumbnailBase64?: string): SessionRecord => {
+    const sessions = readSessions()
+    const now = Date.now()
+    const existing = draft.id ? sessions.find((s) => s.id === draft.id) : undefined
+
+    const record = sessionRecordSchema.parse({
+      ...draft,
+      id: existing?.id ?? randomUUID(),
+      app_version: app.getVersion(),
+      created_at_ms: existing?.created_at_ms ?? now,
+      updated_at_ms: now
+    })
+
+    writeSessions([record, ...sessions.filter((s) => s.id !== record.id)])
+    if (thumbnailBase64) {
+      const thumbPath = getSessionThumbnailPath(record.id)
+      fs.mkdirSync(path.dirname(thumbPath), { recursive: true })
+      fs.writeFileSync(thumbPath, Buffer.from(thumbnailBase64, 'base64'))
+    }
+    log.info('Saved session', { fields: { id: record.id, seed: record.seed_filename } })
+    return record
+  })
+
+  ipcMain.handle('list-sessions', (): SessionRecord[] => {
+    return readSessions().sort((a, b) => b.updated_at_ms - a.updated_at_ms)
+  })
+
+  ipcMain.handle('load-session', (_event, id: string): SessionRecord | null => {
+    return readSessions().find((s) => s.id === id) ?? null
+  })
+
+  ipcMain.handle('delete-session', (_event, id: string): void => {
+    const sessions = readSessions()
+    const remaining = sessions.filter((s) => s.id !== id)
+    if (remaining.length === sessions.length) return
+    writeSessions(remaining)
+    fs.rmSync(getSessionThumbnailPath(id), { force: true })
+    log.info('Deleted session', { fields: { id } })
+  })
+
+  ipcMain.handle('get-session-thumbnail-base64', async (_event, id: string): Promise<string | null> => {
+    if (!readSessions().some((s) => s.id === id)) return null
+    try {
+      return (await fs.promises.readFile(getSessionThumbnailPath(id))).toString('base64')
+    } catch {
+      return null
+    }
+  })
+
+  // Expected failures (missing seed, foreign or incompatible file) come
+  // back as a translated message rather than a rejected invoke.
+  ipcMain.handle(
+    'export-world-file',
+    async (_event, sessionId: string, destPath?: string): Promise<ExportWorldFileResult | null> => {
+      const record = readSessions().find((s) => s.id === sessionId)
+      if (!record) return { ok: false, message: translate('app.dialogs.savedWorlds.worldGone') }
+
+      if (!destPath) {
+        const parentWindow = BrowserWindow.getFocusedWindow() || BrowserWindow.getAllWindows()[0]
+        const stem = path.basename(record.seed_filename, path.extname(record.seed_filename))
+        const saveResult = await dialog.showSaveDialog(parentWindow, {
+          title: translate('app.dialogs.savedWorlds.exportDialogTitle'),
+          defaultPath: path.join(app.getPath('documents'), `${stem}.${WORLD_FILE_EXTENSION}`),
+          filters: [{ name: translate('app.dialogs.savedWorlds.worldFileType'), extensions: [WORLD_FILE_EXTENSION] }]
+        })
+        if (saveResult.canceled || !saveResult.filePath) return null
+        destPath = saveResult.filePath
+      }
+
+      // Renderer-supplied paths may only ever write a `.biomeworld` file.
+      if (path.extname(destPath).toLowerCase() !== `.${WORLD_FILE_EXTENSION}`) {
+        return {
+          ok: false,
+          message: translate('app.dialogs.savedWorlds.wrongExtension', { extension: WORLD_FILE_EXTENSION })
+        }
+      }
+      try {
+        await exportWorldFile(record, destPath)
+      } catch (err) {
+        log.error('Failed to export world file', {
+          exception: err instanceof Error ? (err.stack ?? err.message) : String(err)
+        })
+        return { ok: false, message: err instanceof Error ? err.message : String(err) }
+      }
+      log.info('Exported world file', { fields: { id: record.id, path: destPath } })
+      return { ok: true, file_path: destPath }
+    }
+  )
+
+  ipcMain.handle('import-world-file', async (_event, srcPath?: string): Promise<ImportWorldFileResult | null> => {
+    if (!srcPath) {
+      const parentWindow = BrowserWindow.getFocusedWindow() || BrowserWindow.getAllWindows()[0]
+      const openResult = await dialog.showOpenDialog(parentWindow, {
+        title: translate('app.dialogs.savedWorlds.importDialogTitle'),
+        defaultPath: app.getPath('downloads'),
+        filters: [{ name: translate('app.dialogs.savedWorlds.worldFileType'), extensions: [WORLD_FILE_EXTENSION] }],
+        properties: ['openFile']
+      })
+      if (openResult.canceled || openResult.filePaths.length === 0) return null
+      srcPath = openResult.filePaths[0]
+    }
+
+    let record: SessionRecord
+    try {
+      record = await importWorldFile(srcPath)
+    } catch (err) {
+      log.error('Failed to import world file', {
+        exception: err instanceof Error ? (err.stack ?? err.message) : String(err)
+      })
+      return { ok: false, message: err instanceof Error ? err.message : String(err) }
+    }
+    log.info('Imported world file', { fields: { id: record.id, seed: record.seed_filename } })
+    queueDeepLink({ kind: 'world', seed: record.seed_filename })
+    return { ok: true, session: record }
+  })
+}
@@ -1,16 +1,27 @@
-import { ipcMain, shell } from 'electron'
+import { BrowserWindow, app, dialog, ipcMain, session, shell } from 'electron'
 import fs from 'node:fs'
 import path from 'node:path'
 import { getConfigDir, getSeedsDefaultDir, getSeedsUploadsDir } from '../lib/paths.js'
-import { settingsSchema, DEFAULT_SCENE_ORDER } from '../../src/types/settings.js'
+import { settingsSchema, DEFAULT_SCENE_ORDER, STANDALONE_PORT } from '../../src/types/settings.js'
 import { getLogger } from '../lib/logger.js'
+import { emitToAllWindows } from '../lib/ipcUtils.js'
+import { setMainLocale } from '../lib/i18n.js'
 import type { Settings } from '../../src/types/settings.js'
+import type { ExportSettingsResult, ImportSettingsResult, SettingsIssue } from '../../src/types/ipc.js'
 
 const log = getLogger('electron.settings')
 
 const SETTINGS_FILENAME = 'settings.json'
 const LEGACY_CONFIG_FILENAME = 'config.json'
 
+/** Debounce for the settings.json watcher — editors often save in several
+ *  steps (truncate + write, or write temp + rename). */
+const SETTINGS_WATCH_DEBOUNCE_MS = 250
+
+/** Exact content of the last settings.json we wrote ourselves, so the
+ *  watcher can tell the app's own saves apart from external edits. */
+let lastWrittenContent: string | null = null
+
 function getSettingsPath(): string {
   const configDir = getConfigDir()
   if (!fs.existsSync(configDir)) {
@@ -30,10 +41,11 @@ function migrateFromLegacyConfig(parsed: Record<string, unknown>): Partial<Setti
   const gpuServer = parsed.gpu_server as Record<string, unknown> | undefined
   if (gpuServer) {
     const host = (gpuServer.host as string) || 'localhost'
-    const port = (gpuServer.port as number) || 7987
+    const port = (gpuServer.port as number) || STANDALONE_PORT
     const useSsl = Boolean(gpuServer.use_ssl)
     const protocol = useSsl ? 'https' : 'http'
     migrated.server_url = `${protocol}://${host}:${port}`
+    migrated.server_profiles = [{ name: host, url: migrated.server_url }]
   }
 
   const features = parsed.features as Record<string, unknown> | undefined
@@ -158,15 +170,67 @@ function loadSettings(settingsPath: string): { settings: Settings; dirty: boolea
   return { settings: settingsSchema.parse({}), dirty: true }
 }
 
+function writeSettingsFile(settingsPath: string, settings: Settings): void {
+  const content = JSON.stringify(settings, null, 2)
+  lastWrittenContent = content
+  fs.writeFileSync(settingsPath, content)
+}
+
 export function readSettingsSync(): Settings {
   const settingsPath = getSettingsPath()
   const { settings, dirty } = loadSettings(settingsPath)
   if (dirty) {
-    fs.writeFileSync(settingsPath, JSON.stringify(settings, null, 2))
+    writeSettingsFile(settingsPath, settings)
   }
   return settings
 }
 
+/** Read the `--server <url>` / `--server=<url>` launch argument, if any.
+ *  Takes an argv so the `second-instance` handler can read a later launch's. */
+export function findServerArg(argv: string[]): string | null {
+  for (let i = 0; i < argv.length; i++) {
+    if (argv[i] === '--server' && i + 1 < argv.length) return argv[i + 1]
+    if (argv[i].startsWith('--server=')) return argv[i].slice('--server='.length)
+  }
+  return null
+}
+
+/** Settings forced by the launch environment, for scripted / demo setups
+ *  that need to point at a server without touching settings.json.
+ *  Precedence: `--server`, then `BIOME_SERVER_URL`, then the
+ *  `BIOME_GPU_HOST` / `BIOME_GPU_PORT` / `BIOME_USE_SSL` triple (the same
+ *  shape as the legacy `gpu_server` block). Any of them implies server mode. */
+export function getSettingsOverrides(): Partial<Pick<Settings, 'server_url' | 'engine_mode'>> {
+  const env = process.env
+  let serverUrl = findServerArg(process.argv) ?? env.BIOME_SERVER_URL ?? null
+  if (!serverUrl && env.BIOME_GPU_HOST) {
+    const protocol = ['1', 'true', 'yes'].includes((env.BIOME_USE_SSL ?? '').toLowerCase()) ? 'https' : 'http'
+    serverUrl = `${protocol}://${env.BIOME_GPU_HOST}:${env.BIOME_GPU_PORT || STANDALONE_PORT}`
+  }
+  if (!serverUrl?.trim()) return {}
+  return { server_url: serverUrl.trim(), engine_mode: 'server' }
+}
+
+function applySettingsOverrides(settings: Settings): Settings {
+  return { ...settings, ...getSettingsOverrides() }
+}
+
+/** Overrides are runtime-only: when the renderer saves a settings object
+ *  that still carries an overridden value, keep what was on disk so the
+ *  override doesn't leak into settings.json. A value the user actually
+ *  changed in the UI is persisted as usual. */
+function stripSettingsOverrides(settings: Settings, onDisk: Settings): Settings {
+  const stripped = { ...settings }
+  const overrides = getSettingsOverrides()
+  if (overrides.server_url !== undefined && stripped.server_url === overrides.server_url) {
+    stripped.server_url = onDisk.server_url
+  }
+  if (overrides.engine_mode !== undefined && stripped.engine_mode === overrides.engine_mode) {
+    stripped.engine_mode = onDisk.engine_mode
+  }
+  return stripped
+}
+
 /** Env vars injected into any uv / python subprocess when offline mode is on.
  *  Single source of truth — both engine setup and the server spawn consume this.
  *  `UV_NO_SYNC` (implies `--frozen`) skips uv's resolve + sync passes on
@@ -179,6 +243,203 @@ export function getOfflineEnv(): Record<string, string> {
     : {}
 }
 
+const SERVER_URL_PROTOCOLS = ['http:', 'https:', 'ws:', 'wss:']
+
+type SettingsIssueDetail = Omit<SettingsIssue, 'path'>
+
+const invalidUrl = (value: string): SettingsIssueDetail => ({
+  message_id: 'app.settings.issues.invalidUrl',
+  params: { value },
+  message: `"${value}" is not a valid URL`
+})
+
+/** Mirrors the renderer's `normalizeServerUrl` acceptance rules (bare
+ *  `host:port` gets an implicit `http://`). Returns the issue on failure. */
+export function checkServerUrl(value: string): SettingsIssueDetail | null {
+  const trimmed = value.trim()
+  let parsed: URL
+  try {
+    parsed = new URL(/^[a-z]+:\/\//i.test(trimmed) ? trimmed : `http://${trimmed}`)
+  } catch {
+    return invalidUrl(value)
+  }
+  if (!SERVER_URL_PROTOCOLS.includes(parsed.protocol)) {
+    return {
+      message_id: 'app.settings.issues.unsupportedProtocol',
+      params: { protocol: parsed.protocol },
+      message: `Unsupported protocol "${parsed.protocol}" (expected http, https, ws or wss)`
+    }
+  }
+  if (!parsed.hostname) return { message_id: 'app.settings.issues.missingHost', message: 'URL has no host' }
+  return null
+}
+
+/** Check a candidate settings object without writing it. Schema errors
+ *  keep zod's text as the log detail; on top of that, URLs must parse and a custom
+ *  recording directory must exist. Returns every problem found rather
+ *  than stopping at the first, so the UI can flag all fields at once. */
+export function validateSettings(candidate: unknown): SettingsIssue[] {
+  const result = settingsSchema.safeParse(candidate)
+  if (!result.success) {
+    return result.error.issues.map((issue) => {
+      const path = issue.path.join('.')
+      return { path, message_id: 'app.settings.issues.invalidValue', params: { path }, message: issue.message }
+    })
+  }
+
+  const settings = result.data
+  const issues: SettingsIssue[] = []
+
+  if (settings.server_url.trim()) {
+    const issue = checkServerUrl(settings.server_url)
+    if (issue) issues.push({ path: 'server_url', ...issue })
+  }
+  settings.server_profiles.forEach((profile, i) => {
+    if (!profile.name.trim()) {
+      issues.push({
+        path: `server_profiles.${i}.name`,
+        message_id: 'app.settings.issues.emptyProfileName',
+        message: 'Profile name is empty'
+      })
+    }
+    const issue = checkServerUrl(profile.url)
+    if (issue) issues.push({ path: `server_profiles.${i}.url`, ...issue })
+  })
+
+  for (const [key, value] of Object.entries(settings.network)) {
+    if (!value) continue
+    try {
+      new URL(value)
+    } catch {
+      issues.push({ path: `network.${key}`, ...invalidUrl(value) })
+    }
+  }
+
+  const outputDir = settings.recording.output_dir
+  if (outputDir) {
+    if (!fs.existsSync(outputDir)) {
+      issues.push({
+        path: 'recording.output_dir',
+        message_id: 'app.settings.issues.directoryMissing',
+        params: { path: outputDir },
+        message: `Directory does not exist: ${outputDir}`
+      })
+    } else if (!fs.statSync(outputDir).isDirectory()) {
+      issues.push({
+        path: 'recording.output_dir',
+        message_id: 'app.settings.issues.notADirectory',
+        params: { path: outputDir },
+        message: `Not a directory: ${outputDir}`
+      })
+    }
+  }
+
+  return issues
+}
+
+/** Re-read settings.json after an external edit and push it to the
+ *  renderer. Edits that don't parse or validate are logged and ignored —
+ *  the app keeps running on what it has until the file is fixed. */
+function reloadExternallyEditedSettings(): void {
+  let content: string
+  try {
+    content = fs.readFileSync(getSettingsPath(), 'utf-8')
+  } catch {
+    return // mid-rename; the follow-up event will retry
+  }
+  if (content === lastWrittenContent) return
+
+  let parsed: unknown
+  try {
+    parsed = JSON.parse(content)
+  } catch {
+    log.warning('settings.json was edited but is not valid JSON, ignoring')
+    return
+  }
+
+  const issues = validateSettings(parsed)
+  if (issues.length > 0) {
+    log.warning('settings.json was edited but has problems, ignoring', {
+      fields: { issues: issues.map((i) => `${i.path}: ${i.message}`).join('; ') }
+    })
+    return
+  }
+
+  lastWrittenContent = content
+  log.info('Reloaded settings.json after external edit')
+  const reloaded = settingsSchema.parse(parsed)
+  setMainLocale(reloaded.locale)
+  void applyNetworkProxy(reloaded)
+  emitToAllWindows('settings-changed', applySettingsOverrides(reloaded))
+}
+
+/** Watch the config dir rather than the file itself: editors that save via
+ *  write-temp-then-rename replace the inode, which a file watch loses. */
+function watchSettingsFile(): void {
+  let timer: NodeJS.Timeout | null = null
+  try {
+    fs.watch(getConfigDir(), (_eventType, filename) => {
+      if (filename !== SETTINGS_FILENAME) return
+      if (timer) clearTimeout(timer)
+      timer = setTimeout(reloadExternallyEditedSettings, SETTINGS_WATCH_DEBOUNCE_MS)
+    })
+  } catch (err) {
+    log.warning('Could not watch settings.json; external edits need a restart', {
+      exception: err instanceof Error ? (err.stack ?? err.message) : String(err)
+    })
+  }
+}
+
+/** Env vars injected into any uv / python subprocess from the `network`
+ *  settings. Sibling of `getOfflineEnv`, consumed at the same call sites.
+ *  `NO_PROXY` keeps the renderer ↔ local server traffic off the proxy. */
+export function getNetworkEnv(): Record<string, string> {
+  const { proxy, pypi_mirror, python_mirror, hf_mirror } = readSettingsSync().network
+  const env: Record<string, string> = {}
+  if (proxy) {
+    env.HTTP_PROXY = proxy
+    env.HTTPS_PROXY = proxy
+    env.NO_PROXY = 'localhost,127.0.0.1,::1'
+  }
+  if (pypi_mirror) env.UV_DEFAULT_INDEX = pypi_mirror
+  if (python_mirror) env.UV_PYTHON_INSTALL_MIRROR = python_mirror
+  if (hf_mirror) env.HF_ENDPOINT = hf_mirror
+  return env
+}
+
+/** Base URL uv release archives are downloaded from: the configured
+ *  mirror (laid out like GitHub's `releases/download`), or GitHub itself. */
+export function getUvReleasesBaseUrl(): string {
+  const mirror = readSettingsSync().network.uv_mirror
+  return mirror ? mirror.replace(/\/+$/, '') : 'https://github.com/astral-sh/uv/releases/download'
+}
+
+/** Point Chromium's network stack (used by `net.fetch` in main and by the
+ *  renderer) at the configured proxy, or back to the system settings. */
+async function applyNetworkProxy(settings: Settings): Promise<void> {
+  const proxy = settings.network.proxy
+  try {
+    await session.defaultSession.setProxy(
+      proxy ? { proxyRules: proxy, proxyBypassRules: '<local>' } : { mode: 'system' }
+    )
+  } catch (err) {
+    log.warning('Failed to apply proxy settings', {
+      fields: { proxy },
+      exception: err instanceof Error ? (err.stack ?? err.message) : String(err)
+    })
+  }
+}
+
+/** Show settings.json in the OS file manager, writing defaults first if
+ *  it doesn't exist yet. */
+export function revealSettingsFile(): void {
+  const settingsPath = getSettingsPath()
+  if (!fs.existsSync(settingsPath)) {
+    writeSettingsFile(settingsPath, settingsSchema.parse({}))
+  }
+  shell.showItemInFolder(settingsPath)
+}
+
 export function registerSettingsIpc(): void {
   // Validate default scene files exist at startup
   try {
@@ -190,30 +451,118 @@ export function registerSettingsIpc(): void {
     throw err
   }
 
+  // Populate `lastWrittenContent` (and create the file if needed) before
+  // watching, so the first external edit is diffed against the real state.
+  const initial = readSettingsSync()
+  setMainLocale(initial.locale)
+  void applyNetworkProxy(initial)
+  watchSettingsFile()
+
+  const overrides = getSettingsOverrides()
+  if (overrides.server_url) {
+    log.info('Settings overridden by launch environment', { fields: { server_url: overrides.server_url } })
+  }
+
   ipcMain.handle('read-settings', () => {
-    return readSettingsSync()
+    return applySettingsOverrides(readSettingsSync())
+  })
+
+  ipcMain.handle('get-settings-overrides', () => {
+    return Object.keys(getSettingsOverrides()) as (keyof Settings)[]
   })
 
   ipcMain.handle('read-default-settings', () => {
     return settingsSchema.parse({})
   })
 
-  ipcMain.handle('write-settings', (_event, settings: Settings) => {
+  ipcMain.handle('write-settings', async (_event, settings: Settings) => {
     const settingsPath = getSettingsPath()
-    const validated = settingsSchema.parse(settings)
-    fs.writeFileSync(settingsPath, JSON.stringify(validated, null, 2))
+    const onDisk = readSettingsSync()
+    const validated = stripSettingsOverrides(settingsSchema.parse(settings), onDisk)
+    writeSettingsFile(settingsPath, validated)
+    setMainLocale(validated.locale)
+    if (validated.network.proxy !== onDisk.network.proxy) {
+      await applyNetworkProxy(validated)
+    }
   })
 
-  ipcMain.handle('get-settings-path-str', () => {
-    return getSettingsPath()
+  ipcMain.handle('validate-settings', (_event, settings: unknown) => {
+    return validateSettings(settings)
   })
 
-  ipcMain.handle('open-settings', () => {
-    const settingsPath = getSettingsPath()
-    if (!fs.existsSync(settingsPath)) {
-      const defaults = settingsSchema.parse({})
-      fs.writeFileSync(settingsPath, JSON.stringify(defaults, null, 2))
+  // Settings backup. The exported file is a plain settings.json, so it can
+  // move a setup between machines or be shared as a known-good server
+  // config. Settings hold no credentials, so nothing needs stripping.
+  ipcMain.handle('export-settings', async (): Promise<ExportSettingsResult> => {
+    const parentWindow = BrowserWindow.getFocusedWindow() || BrowserWindow.getAllWindows()[0]
+    const saveResult = await dialog.showSaveDialog(parentWindow, {
+      title: 'Export settings',
+      defaultPath: path.join(app.getPath('documents'), 'biome-settings.json'),
+      filters: [{ name: 'JSON', extensions: ['json'] }]
+    })
+    if (saveResult.canceled || !saveResult.filePath) {
+      return { canceled: true, file_path: null }
     }
-    shell.showItemInFolder(settingsPath)
+
+    await fs.promises.writeFile(saveResult.filePath, JSON.stringify(readSettingsSync(), null, 2), 'utf-8')
+    log.info('Exported settings', { fields: { path: saveResult.filePath } })
+    return { canceled: false, file_path: saveResult.filePath }
   })
+
+  // Replaces settings.json wholesale with the picked file. Older exports go
+  // through the same field migrations as a stale settings.json would.
+  // Returns null when the user cancels and the validation problems when
+  // the file isn't usable.
+  ipcMain.handle('import-settings', async (): Promise<ImportSettingsResult | null> => {
+    const parentWindow = BrowserWindow.getFocusedWindow() || BrowserWindow.getAllWindows()[0]
+    const openResult = await dialog.showOpenDialog(parentWindow, {
+      title: 'Import settings',
+      defaultPath: app.getPath('documents'),
+      filters: [{ name: 'JSON', extensions: ['json'] }],
+      properties: ['openFile']
+    })
+    if (openResult.canceled || openResult.filePaths.length === 0) return null
+
+    const importPath = openResult.filePaths[0]
+    let parsed: unknown
+    try {
+      parsed = JSON.parse(await fs.promises.readFile(importPath, 'utf-8'))
+    } catch {
+      const file = path.basename(importPath)
+      log.warning('Settings import is not valid JSON', { fields: { path: importPath } })
+      return {
+        ok: false,
+        issues: [
+          {
+            path: '',
+            message_id: 'app.settings.issues.invalidJson',
+            params: { file },
+            message: `${file} is not a valid JSON file`
+          }
+        ]
+      }
+    }
+
+    const migrated = migrateSceneAuthoringField(migrateLegacySceneFields(parsed))
+    const issues = validateSettings(migrated)
+    if (issues.length > 0) {
+      log.warning('Settings import has problems', {
+        fields: { path: importPath, issues: issues.map((i) => `${i.path}: ${i.message}`).join('; ') }
+      })
+      return { ok: false, issues }
+    }
+
+    const settings = settingsSchema.parse(migrated)
+    writeSettingsFile(getSettingsPath(), settings)
+    setMainLocale(settings.locale)
+    await applyNetworkProxy(settings)
+    log.info('Imported settings', { fields: { path: importPath } })
+    return { ok: true, settings: applySettingsOverrides(settings) }
+  })
+
+  ipcMain.handle('get-settings-path-str', () => {
+    return getSettingsPath()
+  })
+
+  ipcMain.handle('open-settings', () => revealSettingsFile())
 }
@@ -0,0 +1,70 @@
+import { BrowserWindow, Menu, Tray, app, ipcMain, nativeImage } from 'electron'
+import { emitToAllWindows } from '../lib/ipcUtils.js'
+import { onMainLocaleChange, translate, type MainTranslationKey } from '../lib/i18n.js'
+import { revealSettingsFile } from './settings.js'
+import { resetWindowState } from './window.js'
+import type { TrayEngineState } from '../../src/types/ipc.js'
+
+const TRAY_ICON_SIZE_PX = 16
+
+const ENGINE_STATE_LABELS: Record<TrayEngineState, MainTranslationKey> = {
+  stopped: 'app.tray.engineStopped',
+  starting: 'app.tray.engineStarting',
+  ready: 'app.tray.engineReady',
+  error: 'app.tray.engineError'
+}
+
+let tray: Tray | null = null
+let engineState: TrayEngineState = 'stopped'
+
+function showMainWindow(): void {
+  const win = BrowserWindow.getAllWindows()[0]
+  if (!win) return
+  if (win.isMinimized()) win.restore()
+  win.show()
+  win.focus()
+}
+
+function rebuildTrayMenu(): void {
+  if (!tray) return
+  const stateLabel = translate(ENGINE_STATE_LABELS[engineState])
+  tray.setToolTip(translate('app.tray.tooltip', { state: stateLabel }))
+  tray.setContextMenu(
+    Menu.buildFromTemplate([
+      { label: stateLabel, enabled: false },
+      { type: 'separator' },
+      { label: translate('app.tray.show'), click: showMainWindow },
+      // The renderer owns the engine lifecycle; a restart goes through it
+      // so the lifecycle state stays honest.
+      {
+        label: translate('app.tray.restartEngine'),
+        enabled: engineState !== 'starting',
+        click: () => emitToAllWindows('tray-restart-engine')
+      },
+      { label: translate('app.tray.openConfig'), click: revealSettingsFile },
+      // The way back when a restored window ends up somewhere unreachable.
+      { label: translate('app.tray.resetWindowPosition'), click: resetWindowState },
+      { type: 'separator' },
+      { label: translate('app.tray.quit'), click: () => app.quit() }
+    ])
+  )
+}
+
+/** Create the tray icon. Clicking it brings the window back, which is the
+ *  only way back in once `minimize_to_tray` has hidden it. */
+export function createTray(appIcon: string | Electron.NativeImage | undefined): void {
+  if (tray || !appIcon) return
+  const image = typeof appIcon === 'string' ? nativeImage.createFromPath(appIcon) : appIcon
+  tray = new Tray(image.resize({ width: TRAY_ICON_SIZE_PX, height: TRAY_ICON_SIZE_PX }))
+  tray.on('click', showMainWindow)
+  onMainLocaleChange(rebuildTrayMenu)
+  rebuildTrayMenu()
+}
+
+export function registerTrayIpc(): void {
+  ipcMain.handle('set-tray-engine-state', (_event, state: TrayEngineState) => {
+    if (!(state in ENGINE_STATE_LABELS) || state === engineState) return
+    engineState = state
+    rebuildTrayMenu()
+  })
+}
@@ -1,9 +1,28 @@
-import { app, ipcMain } from 'electron'
+import { app, ipcMain, net } from 'electron'
 import { getLogger } from '../lib/logger.js'
+import { emitToAllWindows } from '../lib/ipcUtils.js'
+import { readSettingsSync } from './settings.js'
+import type { UpdateChannel } from '../../src/types/settings.js'
+import type { AppUpdateInfo } from '../../src/types/ipc.js'
 
 const log = getLogger('electron.update')
 
-const RELEASES_API_URL = 'https://api.github.com/repos/Overworldai/Biome/releases/latest'
+const RELEASES_API_URL = 'https://api.github.com/repos/Overworldai/Biome/releases'
+// `/releases/latest` never returns prereleases or drafts — exactly the
+// stable channel. The beta channel scans the most recent page instead.
+const LATEST_RELEASE_API_URL = `${RELEASES_API_URL}/latest`
+const BETA_RELEASES_PAGE_SIZE = 20
+
+/** Newest version the renderer has already been told about, so a periodic
+ *  re-check doesn't pop the same prompt again after it was dismissed. */
+let announcedVersion: string | null = null
+
+type GitHubRelease = {
+  tag_name?: string
+  html_url?: string
+  draft?: boolean
+  prerelease?: boolean
+}
 
 type ParsedVersion = {
   segments: number[]
@@ -44,56 +63,97 @@ function compareVersions(a: string, b: string): number {
   return (aParsed.prerelease || '').localeCompare(bParsed.prerelease || '', undefined, { sensitivity: 'base' })
 }
 
-export function registerUpdateIpc(): void {
-  ipcMain.handle('check-for-app-update', async () => {
-    const currentVersion = app.getVersion()
-
-    if (!app.isPackaged) {
-      return {
-        current_version: currentVersion,
-        latest_version: currentVersion,
-        release_url: null,
-        update_available: false
-      }
+async function fetchGitHubJson(url: string, currentVersion: string): Promise<unknown> {
+  // `net.fetch` so the check goes through the configured proxy, like the
+  // uv download does.
+  const response = await net.fetch(url, {
+    headers: {
+      Accept: 'application/vnd.github+json',
+      'User-Agent': `Biome/${currentVersion}`
+    }
+  })
+
+  if (!response.ok) {
+    throw new Error(`GitHub API request failed with HTTP ${response.status}`)
+  }
+
+  return response.json()
+}
+
+/** Newest release visible on `channel`, or null if GitHub returned none. */
+async function fetchLatestRelease(channel: UpdateChannel, currentVersion: string): Promise<GitHubRelease | null> {
+  if (channel === 'stable') {
+    return (await fetchGitHubJson(LATEST_RELEASE_API_URL, currentVersion)) as GitHubRelease
+  }
+
+  const url = `${RELEASES_API_URL}?per_page=${BETA_RELEASES_PAGE_SIZE}`
+  const releases = (await fetchGitHubJson(url, currentVersion)) as GitHubRelease[]
+  let newest: GitHubRelease | null = null
+  for (const release of releases) {
+    if (release.draft || !release.tag_name) continue
+    if (!newest?.tag_name || compareVersions(release.tag_name, newest.tag_name) > 0) {
+      newest = release
+    }
+  }
+  return newest
+}
+
+/** Compare the running version against the newest release on the
+ *  configured channel. Never throws: a failed check reports no update. */
+export async function checkForAppUpdate(): Promise<AppUpdateInfo> {
+  const currentVersion = app.getVersion()
+
+  if (!app.isPackaged) {
+    return {
+      current_version: currentVersion,
+      latest_version: currentVersion,
+      release_url: null,
+      update_available: false
     }
+  }
+
+  try {
+    const channel = readSettingsSync().update_channel
+    const body = await fetchLatestRelease(channel, currentVersion)
 
-    try {
-      const response = await fetch(RELEASES_API_URL, {
-        headers: {
-          Accept: 'application/vnd.github+json',
-          'User-Agent': `Biome/${currentVersion}`
-        }
-      })
-
-      if (!response.ok) {
-        throw new Error(`GitHub API request failed with HTTP ${response.status}`)
-      }
-
-      const body = (await response.json()) as {
-        tag_name?: string
-        html_url?: string
-      }
-
-      const latestVersion = body.tag_name?.trim() || currentVersion
-      const releaseUrl = body.html_url?.trim() || null
-      const updateAvailable = compareVersions(latestVersion, currentVersion) > 0
-
-      return {
-        current_version: currentVersion,
-        latest_version: latestVersion,
-        release_url: releaseUrl,
-        update_available: updateAvailable
-      }
-    } catch (error) {
-      log.warning('Failed to check for new release', {
-        exception: error instanceof Error ? (error.stack ?? error.message) : String(error)
-      })
-      return {
-        current_version: currentVersion,
-        latest_version: currentVersion,
-        release_url: null,
-        update_available: false
-      }
+    const latestVersion = body?.tag_name?.trim() || currentVersion
+    const releaseUrl = body?.html_url?.trim() || null
+    const updateAvailable = compareVersions(latestVersion, currentVersion) > 0
+
+    return {
+      current_version: currentVersion,
+      latest_version: latestVersion,
+      release_url: releaseUrl,
+      update_available: updateAvailable
+    }
+  } catch (error) {
+    log.warning('Failed to check for new release', {
+      exception: error instanceof Error ? (error.stack ?? error.message) : String(error)
+    })
+    return {
+      current_version: currentVersion,
+      latest_version: currentVersion,
+      release_url: null,
+      update_available: false
     }
+  }
+}
+
+/** Background re-check for long-running sessions. Only a release newer
+ *  than the one already announced is pushed to the renderer. */
+export async function refreshUpdateCheck(): Promise<AppUpdateInfo> {
+  const info = await checkForAppUpdate()
+  if (info.update_available && info.latest_version !== announcedVersion) {
+    announcedVersion = info.latest_version
+    emitToAllWindows('app-update-available', info)
+  }
+  return info
+}
+
+export function registerUpdateIpc(): void {
+  ipcMain.handle('check-for-app-update', async () => {
+    const info = await checkForAppUpdate()
+    if (info.update_available) announcedVersion = info.latest_version
+    return info
   })
 }
@@ -1,7 +1,232 @@
-import { ipcMain, BrowserWindow, app, screen } from 'electron'
+import { ipcMain, BrowserWindow, app, powerSaveBlocker, screen } from 'electron'
+import fs from 'node:fs'
+import path from 'node:path'
+import { getConfigDir } from '../lib/paths.js'
+import { getLogger } from '../lib/logger.js'
+import { readSettingsSync } from './settings.js'
+import type { MonitorInfo } from '../../src/types/ipc.js'
+
+const log = getLogger('electron.window')
 
 const savedBoundsByWindowId = new Map<number, Electron.Rectangle>()
 
+const WINDOW_ASPECT_RATIO = 16 / 9
+export const DEFAULT_WINDOW_SIZE = { width: 1280, height: 720 }
+
+const WINDOW_STATE_FILENAME = 'window-state.json'
+
+/** Geometry persisted across launches. `bounds` are always the normal
+ *  (un-maximized, non-fullscreen) bounds; the flags are re-applied on top. */
+export type WindowState = {
+  bounds: Electron.Rectangle
+  maximized: boolean
+  fullscreen: boolean
+  displayId: number
+}
+
+/** What `enter-session-mode` changed, so `exit-session-mode` can put it
+ *  back. Lives in main so it survives the renderer losing focus. */
+type SessionModeState = {
+  windowId: number
+  bounds: Electron.Rectangle
+  powerSaveBlockerId: number
+}
+
+let sessionMode: SessionModeState | null = null
+
+function getTargetWindow(): BrowserWindow | undefined {
+  return BrowserWindow.getFocusedWindow() || BrowserWindow.getAllWindows()[0]
+}
+
+function listMonitors(): MonitorInfo[] {
+  const primaryId = screen.getPrimaryDisplay().id
+  return screen.getAllDisplays().map((display, index) => ({
+    id: display.id,
+    // Linux often reports an empty label.
+    label: display.label || `Display ${index + 1}`,
+    width: display.size.width,
+    height: display.size.height,
+    primary: display.id === primaryId
+  }))
+}
+
+/** Borderless fullscreen on `displayId` (else `display.preferred_monitor`,
+ *  else the window's current display) with display sleep inhibited. The
+ *  cursor is left to pointer lock, which hides it during play and gives it
+ *  back for the pause menu. */
+function enterSessionMode(win: BrowserWindow, displayId?: number): void {
+  if (sessionMode) return
+
+  const targetId = displayId ?? readSettingsSync().display.preferred_monitor
+  const bounds = win.getBounds()
+  const display = screen.getAllDisplays().find((d) => d.id === targetId) ?? screen.getDisplayMatching(bounds)
+
+  sessionMode = {
+    windowId: win.id,
+    bounds,
+    powerSaveBlockerId: powerSaveBlocker.start('prevent-display-sleep')
+  }
+
+  // The aspect-ratio lock would fight a fullscreen window on a non-16:9
+  // display; the renderer letterboxes instead.
+  win.setAspectRatio(0)
+  win.setBounds(display.bounds)
+  win.setFullScreen(true)
+}
+
+/** Re-home an active session on another display. The restore bounds are
+ *  kept, so exiting still returns the window to where it started. Leaving
+ *  fullscreen is asynchronous on macOS and drops a `setBounds` made before
+ *  it finishes, so the move waits for `leave-full-screen` first. */
+async function moveSessionToMonitor(displayId: number): Promise<boolean> {
+  if (!sessionMode) return false
+  const display = screen.getAllDisplays().find((d) => d.id === displayId)
+  if (!display) throw new Error(`Unknown monitor: ${displayId}`)
+
+  const windowId = sessionMode.windowId
+  const win = BrowserWindow.fromId(windowId)
+  if (!win || win.isDestroyed()) return false
+
+  if (win.isFullScreen()) {
+    const left = new Promise<void>((resolve) => {
+      win.once('leave-full-screen', () => resolve())
+      win.once('closed', () => resolve())
+    })
+    win.setFullScreen(false)
+    await left
+  }
+  // The session may have ended (or the window closed) while we waited.
+  if (sessionMode?.windowId !== windowId || win.isDestroyed()) return false
+
+  win.setBounds(display.bounds)
+  win.setFullScreen(true)
+  return true
+}
+
+function exitSessionMode(): void {
+  if (!sessionMode) return
+  const { windowId, bounds, powerSaveBlockerId } = sessionMode
+  sessionMode = null
+
+  powerSaveBlocker.stop(powerSaveBlockerId)
+  const win = BrowserWindow.fromId(windowId)
+  if (!win || win.isDestroyed()) return
+
+  win.setFullScreen(false)
+  win.setAspectRatio(WINDOW_ASPECT_RATIO)
+  win.setBounds(bounds)
+}
+
+function getWindowStatePath(): string {
+  return path.join(getConfigDir(), WINDOW_STATE_FILENAME)
+}
+
+function isRectangle(value: unknown): value is Electron.Rectangle {
+  if (!value || typeof value !== 'object') return false
+  const rect = value as Record<string, unknown>
+  return ['x', 'y', 'width', 'height'].every((key) => Number.isFinite(rect[key]))
+}
+
+function intersects(a: Electron.Rectangle, b: Electron.Rectangle): boolean {
+  return a.x < b.x + b.width && b.x < a.x + a.width && a.y < b.y + b.height && b.y < a.y + a.height
+}
+
+function centerOn(workArea: Electron.Rectangle, width: number, height: number): Electron.Rectangle {
+  width = Math.min(width, workArea.width)
+  height = Math.min(height, workArea.height)
+  return {
+    x: workArea.x + Math.floor((workArea.width - width) / 2),
+    y: workArea.y + Math.floor((workArea.height - height) / 2),
+    width,
+    height
+  }
+}
+
+/** Read the last saved window state. If the saved bounds no longer land on
+ *  any display (monitor unplugged, resolution changed), the size is kept and
+ *  the window is re-centered on the remembered display, or the primary one. */
+export function loadWindowState(): WindowState | null {
+  let parsed: Partial<WindowState>
+  try {
+    parsed = JSON.parse(fs.readFileSync(getWindowStatePath(), 'utf-8')) as Partial<WindowState>
+  } catch {
+    return null
+  }
+  const saved = parsed.bounds
+  if (!isRectangle(saved)) return null
+
+  const displays = screen.getAllDisplays()
+  const display = displays.find((d) => d.id === parsed.displayId) ?? screen.getPrimaryDisplay()
+  const onScreen = displays.some((d) => intersects(saved, d.workArea))
+  const bounds = onScreen ? saved : centerOn(display.workArea, saved.width, saved.height)
+
+  return {
+    bounds,
+    maximized: parsed.maximized === true,
+    fullscreen: parsed.fullscreen === true,
+    displayId: display.id
+  }
+}
+
+/** Snapshot a window for persistence. Session mode and the aspect-fit
+ *  maximize both move the window, so their remembered bounds win. */
+function captureWindowState(win: BrowserWindow): WindowState {
+  const maximizedFrom = savedBoundsByWindowId.get(win.id)
+  const inSessionMode = sessionMode?.windowId === win.id
+  const bounds = maximizedFrom ?? (inSessionMode ? sessionMode!.bounds : win.getNormalBounds())
+
+  return {
+    bounds,
+    maximized: maximizedFrom !== undefined,
+    fullscreen: !inSessionMode && win.isFullScreen(),
+    displayId: screen.getDisplayMatching(bounds).id
+  }
+}
+
+function saveWindowState(win: BrowserWindow): void {
+  try {
+    fs.mkdirSync(getConfigDir(), { recursive: true })
+    fs.writeFileSync(getWindowStatePath(), JSON.stringify(captureWindowState(win), null, 2))
+  } catch (err) {
+    log.warning('Failed to save window state', {
+      exception: err instanceof Error ? (err.stack ?? err.message) : String(err)
+    })
+  }
+}
+
+/** Re-apply the maximized / fullscreen flags of a restored state and save
+ *  the window's geometry whenever it closes (or hides to the tray). */
+export function trackWindowState(win: BrowserWindow, restored: WindowState | null): void {
+  if (restored?.maximized) {
+    savedBoundsByWindowId.set(win.id, restored.bounds)
+    win.setBounds(getMaxAspectFitBounds(win, WINDOW_ASPECT_RATIO))
+  }
+  if (restored?.fullscreen) {
+    // The window starts hidden until the renderer is ready; going
+    // fullscreen before that would show it early on some platforms.
+    win.once('show', () => win.setFullScreen(true))
+  }
+
+  win.on('close', () => saveWindowState(win))
+}
+
+/** Forget the saved state and put the window back at its default size,
+ *  centered on the primary display. */
+export function resetWindowState(): void {
+  fs.rmSync(getWindowStatePath(), { force: true })
+
+  const win = getTargetWindow()
+  if (!win) return
+
+  exitSessionMode()
+  savedBoundsByWindowId.delete(win.id)
+  if (win.isFullScreen()) win.setFullScreen(false)
+  if (win.isMinimized()) win.restore()
+  win.setBounds(centerOn(screen.getPrimaryDisplay().workArea, DEFAULT_WINDOW_SIZE.width, DEFAULT_WINDOW_SIZE.height))
+  win.show()
+  win.focus()
+}
+
 function getMaxAspectFitBounds(win: BrowserWindow, aspectRatio: number): Electron.Rectangle {
   const currentBounds = win.getBounds()
   const display = screen.getDisplayMatching(currentBounds)
@@ -43,7 +268,7 @@ export function registerWindowIpc(): void {
       const [width, height] = win.getSize()
       return { width, height }
     }
-    return { width: 1280, height: 720 }
+    return DEFAULT_WINDOW_SIZE
   })
 
   ipcMain.handle('window-minimize', (_event) => {
@@ -67,14 +292,13 @@ export function registerWindowIpc(): void {
     }
 
     savedBoundsByWindowId.set(windowId, win.getBounds())
-    const fitBounds = getMaxAspectFitBounds(win, 16 / 9)
+    const fitBounds = getMaxAspectFitBounds(win, WINDOW_ASPECT_RATIO)
     win.setBounds(fitBounds)
   })
 
   ipcMain.handle('window-close', (_event) => {
     const win = BrowserWindow.getFocusedWindow() || BrowserWindow.getAllWindows()[0]
     if (win) {
-      savedBoundsByWindowId.delete(win.id)
       win.close()
     }
   })
@@ -83,6 +307,31 @@ export function registerWindowIpc(): void {
     app.quit()
   })
 
+  ipcMain.handle('enter-session-mode', (_event, displayId?: number) => {
+    const win = getTargetWindow()
+    if (win) enterSessionMode(win, displayId)
+  })
+
+  ipcMain.handle('exit-session-mode', () => exitSessionMode())
+
+  ipcMain.handle('list-monitors', () => listMonitors())
+
+  ipcMain.handle('move-session-to-monitor', (_event, displayId: number) => moveSessionToMonitor(displayId))
+
+  ipcMain.handle('reset-window-state', () => resetWindowState())
+
+  // Never leave display sleep inhibited behind a closed window or a quit
+  // that skipped the renderer's exit call. Maximize bounds are dropped here
+  // rather than on close so the window-state save can still read them.
+  app.on('browser-window-created', (_event, win) => {
+    const windowId = win.id
+    win.on('closed', () => {
+      savedBoundsByWindowId.delete(windowId)
+      if (sessionMode?.windowId === windowId) exitSessionMode()
+    })
+  })
+  app.on('will-quit', () => exitSessionMode())
+
   ipcMain.handle('window-set-position', (_event, x: number, y: number) => {
     const win = BrowserWindow.getFocusedWindow() || BrowserWindow.getAllWindows()[0]
     if (win) {
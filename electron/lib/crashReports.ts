@@ -0,0 +1,75 @@
+import { app, crashReporter } from 'electron'
+import fs from 'node:fs'
+import path from 'node:path'
+import { getCrashReportsDir } from './paths.js'
+import { getRecentElectronLogs } from './logger.js'
+import type { CrashReportEntry } from '../../src/types/ipc.js'
+
+/** How many trailing Electron log records ride along in a crash report —
+ *  enough to see the setup / server phase that preceded the crash. */
+const CRASH_REPORT_LOG_TAIL = 200
+
+/** Start Chromium's crash reporter in local-only mode so native crashes of
+ *  any Electron process leave a minidump under `app.getPath('crashDumps')`.
+ *  Nothing is uploaded; the renderer offers the files for attaching to an
+ *  issue via `list-crash-reports`. Must run before `app.whenReady()`. */
+export function startCrashReporter(): void {
+  crashReporter.start({ uploadToServer: false, compress: true })
+}
+
+/** Synchronously write a JSON crash report. Sync on purpose: callers
+ *  include `uncaughtException`, where async I/O can't be trusted to
+ *  complete before the process exits. Never throws. */
+export function writeCrashReport(kind: string, details: Record<string, string | number>): void {
+  try {
+    const dir = getCrashReportsDir()
+    fs.mkdirSync(dir, { recursive: true })
+    const ts = new Date().toISOString().replace(/[:.]/g, '-').replace('T', '_').slice(0, 23)
+    const report = {
+      kind,
+      created_at: new Date().toISOString(),
+      app_version: app.getVersion(),
+      platform: process.platform,
+      arch: process.arch,
+      details,
+      electron_logs: getRecentElectronLogs().slice(-CRASH_REPORT_LOG_TAIL)
+    }
+    fs.writeFileSync(path.join(dir, `crash_${ts}_${kind}.json`), JSON.stringify(report, null, 2))
+  } catch {
+    // Best-effort: a failing crash report must not mask the original crash.
+  }
+}
+
+function collectFiles(
+  dir: string,
+  matches: (name: string) => boolean,
+  kind: CrashReportEntry['kind']
+): CrashReportEntry[] {
+  if (!fs.existsSync(dir)) return []
+  const entries: CrashReportEntry[] = []
+  for (const entry of fs.readdirSync(dir, { withFileTypes: true })) {
+    const fullPath = path.join(dir, entry.name)
+    // Crashpad nests dumps under `completed/` / `pending/` (and `reports/`
+    // on Windows), so walk subdirectories.
+    if (entry.isDirectory()) {
+      entries.push(...collectFiles(fullPath, matches, kind))
+      continue
+    }
+    if (!entry.isFile() || !matches(entry.name)) continue
+    try {
+      const stat = fs.statSync(fullPath)
+      entries.push({ path: fullPath, kind, size_bytes: stat.size, created_at_ms: stat.mtimeMs })
+    } catch {
+      // skip unreadable entries
+    }
+  }
+  return entries
+}
+
+/** JSON reports written by `writeCrashReport` plus native minidumps,
+ *  newest first. */
+export function listCrashReports(): CrashReportEntry[] {
+  const reports = collectFiles(getCrashReportsDir(), (name) => name.endsWith('.json'), 'report')
+  const minidumps = collectFiles(app.getPath('crashDumps'), (name) => name.endsWith('.dmp'), 'minidump')
+  return [...reports, ...minidumps].sort((a, b) => b.created_at_ms - a.created_at_ms)
+}
@@ -0,0 +1,36 @@
+import { safeStorage } from 'electron'
+import fs from 'node:fs'
+import path from 'node:path'
+import { getConfigDir } from './paths.js'
+
+const HF_TOKEN_FILENAME = 'hf_token.bin'
+
+function getHfTokenPath(): string {
+  return path.join(getConfigDir(), HF_TOKEN_FILENAME)
+}
+
+/** The HuggingFace token saved through the settings UI, decrypted, or
+ *  null if none is saved (or it can no longer be decrypted, e.g. after an
+ *  OS keychain reset). Encrypted with `safeStorage`, so the key lives in
+ *  the OS keychain / DPAPI / libsecret rather than next to the file. */
+export function getStoredHfToken(): string | null {
+  const tokenPath = getHfTokenPath()
+  if (!fs.existsSync(tokenPath) || !safeStorage.isEncryptionAvailable()) return null
+  try {
+    return safeStorage.decryptString(fs.readFileSync(tokenPath)) || null
+  } catch {
+    return null
+  }
+}
+
+export function storeHfToken(token: string): void {
+  if (!safeStorage.isEncryptionAvailable()) {
+    throw new Error('No OS keychain is available to store the token securely')
+  }
+  fs.mkdirSync(getConfigDir(), { recursive: true })
+  fs.writeFileSync(getHfTokenPath(), safeStorage.encryptString(token))
+}
+
+export function clearStoredHfToken(): void {
+  fs.rmSync(getHfTokenPath(), { force: true })
+}
@@ -0,0 +1,52 @@
+import { app } from 'electron'
+import { resources } from '../../src/i18n/resources.js'
+import { FALLBACK_LOCALE, isSupportedLocale, type SupportedLocale } from '../../src/i18n/locales.js'
+
+// Strings the main process shows itself (tray menu, native notifications,
+// file dialogs) come from the renderer's locale files, so there is one set
+// of translations to maintain. The locale follows the `locale` setting;
+// `electron/ipc/settings.ts` pushes it in through `setMainLocale`.
+
+type TranslationTree = (typeof resources)[typeof FALLBACK_LOCALE]['translation']
+
+type LeafPaths<T, Prefix extends string = ''> = {
+  [K in keyof T & string]: T[K] extends string ? `${Prefix}${K}` : LeafPaths<T[K], `${Prefix}${K}.`>
+}[keyof T & string]
+
+/** Dot-path of any string in the locale files (`app.tray.quit`). */
+export type MainTranslationKey = LeafPaths<TranslationTree>
+
+let locale: SupportedLocale = FALLBACK_LOCALE
+const localeListeners = new Set<() => void>()
+
+/** Resolve the `locale` setting (`system` follows the OS) and notify
+ *  listeners when it changes, so long-lived UI like the tray menu can
+ *  rebuild its labels. */
+export function setMainLocale(setting: string): void {
+  const candidate = setting !== 'system' ? setting : app.getLocale().toLowerCase().split('-')[0]
+  const next = isSupportedLocale(candidate) ? candidate : FALLBACK_LOCALE
+  if (next === locale) return
+  locale = next
+  localeListeners.forEach((listener) => listener())
+}
+
+export function onMainLocaleChange(listener: () => void): void {
+  localeListeners.add(listener)
+}
+
+function lookup(tree: unknown, key: string): string | null {
+  let node = tree
+  for (const part of key.split('.')) {
+    if (typeof node !== 'object' || node === null) return null
+    node = (node as Record<string, unknown>)[part]
+  }
+  return typeof node === 'string' ? node : null
+}
+
+/** Main-process `t()`: looks the key up in the current locale, falls back
+ *  to English, and fills `{{param}}` placeholders. */
+export function translate(key: MainTranslationKey, params: Record<string, string | number> = {}): string {
+  const template =
+    lookup(resources[locale].translation, key) ?? lookup(resources[FALLBACK_LOCALE].translation, key) ?? key
+  return template.replace(/\{\{(\w+)\}\}/g, (match, name: string) => (name in params ? String(params[name]) : match))
+}
@@ -0,0 +1,57 @@
+import fs from 'node:fs'
+import path from 'node:path'
+import { getExeDir } from './paths.js'
+
+// The in-process install guard can't see a second Biome process (a
+// `--headless setup` skips the single-instance lock), so installs also take
+// an exclusive lockfile next to the engine and uv directories. It lives in
+// the data dir rather than the engine dir so a nuke-and-reinstall can't
+// delete it out from under its owner.
+const INSTALL_LOCK_FILENAME = '.install.lock'
+
+function getInstallLockPath(): string {
+  return path.join(getExeDir(), INSTALL_LOCK_FILENAME)
+}
+
+function isProcessAlive(pid: number): boolean {
+  try {
+    process.kill(pid, 0)
+    return true
+  } catch (err) {
+    // EPERM: alive, just owned by someone else.
+    return (err as NodeJS.ErrnoException).code === 'EPERM'
+  }
+}
+
+/** Take the cross-process install lock. Returns false when another live
+ *  process holds it; a lock left behind by a process that died is reclaimed. */
+export function acquireInstallLock(): boolean {
+  const lockPath = getInstallLockPath()
+  fs.mkdirSync(path.dirname(lockPath), { recursive: true })
+  for (let attempt = 0; attempt < 2; attempt++) {
+    try {
+      fs.writeFileSync(lockPath, String(process.pid), { flag: 'wx' })
+      return true
+    } catch (err) {
+      if ((err as NodeJS.ErrnoException).code !== 'EEXIST') throw err
+    }
+    let owner: number
+    try {
+      owner = Number.parseInt(fs.readFileSync(lockPath, 'utf-8'), 10)
+    } catch {
+      continue // Released between our write and this read
+    }
+    if (Number.isInteger(owner) && owner !== process.pid && isProcessAlive(owner)) return false
+    fs.rmSync(lockPath, { force: true })
+  }
+  return false
+}
+
+export function releaseInstallLock(): void {
+  const lockPath = getInstallLockPath()
+  try {
+    if (fs.readFileSync(lockPath, 'utf-8') === String(process.pid)) fs.rmSync(lockPath, { force: true })
+  } catch {
+    // Already gone
+  }
+}
@@ -1,5 +1,9 @@
+import { app } from 'electron'
+import fs from 'node:fs'
+import path from 'node:path'
 import type { LogRecord } from '../../src/types/ipc.js'
 import { emitToAllWindows } from './ipcUtils.js'
+import { parseLogLine } from './logRecord.js'
 
 /** Electron-side structured logger.  Mirrors the Python server's
  *  `util/server_logging.py` setup so a developer reading either side's
@@ -24,7 +28,11 @@ import { emitToAllWindows } from './ipcUtils.js'
  *  When `broadcast: true` is passed (or set as the logger's default),
  *  the same call also `emitToAllWindows('engine-log', record)` so the
  *  renderer's log buffer and the diagnostic export pick it up alongside
- *  Python events. */
+ *  Python events.
+ *
+ *  Every record is also appended to a persistent, size-rotated JSON-Lines
+ *  file (`biome.log` under Electron's logs dir) so a failed setup can be
+ *  reconstructed after the app has been restarted. */
 
 type LogLevel = 'debug' | 'info' | 'warning' | 'error'
 
@@ -84,6 +92,119 @@ function renderTextLine(record: LogRecord): string {
   return line
 }
 
+const LOG_FILE_NAME = 'biome.log'
+const LOG_FILE_MAX_BYTES = 5 * 1024 * 1024
+/** Rotated generations kept alongside the live file (`biome.log.1` …). */
+const LOG_FILE_KEEP = 3
+
+/** Set after the first write failure (read-only dir, full disk) so a
+ *  broken log file doesn't throw on every subsequent log call. */
+let logFileDisabled = false
+
+/** Size of the live log file, read once on the first flush and then
+ *  tracked so the hot path never stats the file. */
+let logFileBytes: number | null = null
+
+/** Lines waiting for the next flush. Writes are batched per tick and
+ *  appended synchronously, so nothing is ever in flight on a background
+ *  thread when the process exits. */
+let pendingLogLines: string[] = []
+let logFlushScheduled = false
+
+/** Path of the persistent Electron-side log file. */
+export function getAppLogPath(): string {
+  return path.join(app.getPath('logs'), LOG_FILE_NAME)
+}
+
+function rotateLogFileIfNeeded(logPath: string): void {
+  let size: number
+  try {
+    size = fs.statSync(logPath).size
+  } catch {
+    return
+  }
+  if (size < LOG_FILE_MAX_BYTES) return
+
+  for (let i = LOG_FILE_KEEP - 1; i >= 1; i -= 1) {
+    const src = `${logPath}.${i}`
+    if (fs.existsSync(src)) fs.renameSync(src, `${logPath}.${i + 1}`)
+  }
+  fs.renameSync(logPath, `${logPath}.1`)
+}
+
+function disableLogFile(err: unknown): void {
+  if (logFileDisabled) return
+  logFileDisabled = true
+  pendingLogLines = []
+  process.stderr.write(`Persistent log file disabled: ${err instanceof Error ? err.message : String(err)}\n`)
+}
+
+/** Append every pending line to the log file now, rotating first when the
+ *  file is full. Error records flush through here immediately, and callers
+ *  must call it before `process.exit`, so a crash can't drop the lines
+ *  that explain it. */
+export function flushLogFileSync(): void {
+  if (logFileDisabled || pendingLogLines.length === 0) return
+  const chunk = pendingLogLines.join('')
+  pendingLogLines = []
+  try {
+    const logPath = getAppLogPath()
+    if (logFileBytes === null) {
+      fs.mkdirSync(path.dirname(logPath), { recursive: true })
+      rotateLogFileIfNeeded(logPath)
+      logFileBytes = fs.existsSync(logPath) ? fs.statSync(logPath).size : 0
+    } else if (logFileBytes >= LOG_FILE_MAX_BYTES) {
+      rotateLogFileIfNeeded(logPath)
+      logFileBytes = 0
+    }
+    fs.appendFileSync(logPath, chunk)
+    logFileBytes += Buffer.byteLength(chunk)
+  } catch (err) {
+    disableLogFile(err)
+  }
+}
+
+function writeLogLine(line: string, sync: boolean): void {
+  if (logFileDisabled) return
+  pendingLogLines.push(line)
+  if (sync) {
+    flushLogFileSync()
+    return
+  }
+  if (logFlushScheduled) return
+  logFlushScheduled = true
+  setImmediate(() => {
+    logFlushScheduled = false
+    flushLogFileSync()
+  })
+}
+
+/** Append a record as one flat structlog-shaped JSON line — the same
+ *  shape `parseLogLine` reads back — with a full ISO timestamp, since
+ *  the file outlives a single day. Error records are written before this
+ *  returns; everything else is batched until the end of the tick. */
+function appendToLogFile(record: LogRecord): void {
+  if (logFileDisabled) return
+  const { fields, ...rest } = record
+  const line = JSON.stringify({ ...rest, ...fields, timestamp: new Date().toISOString() }) + '\n'
+  writeLogLine(line, record.level === 'error')
+}
+
+/** Last `maxLines` records from the persistent log file, oldest first.
+ *  Spills into the most recent rotated generation when the live file is
+ *  shorter than requested (e.g. just after a rotation). */
+export function readRecentAppLogs(maxLines: number): LogRecord[] {
+  const logPath = getAppLogPath()
+  const lines: string[] = []
+  for (const candidate of [logPath, `${logPath}.1`]) {
+    if (lines.length >= maxLines) break
+    if (!fs.existsSync(candidate)) continue
+    const fileLines = fs.readFileSync(candidate, 'utf-8').split('\n').filter((l) => l.trim())
+    lines.unshift(...fileLines.slice(-(maxLines - lines.length)))
+  }
+  return lines.map((line) => parseLogLine(line, false))
+}
+
 /** Rolling buffer of `LogRecord`s produced on the Electron side.
  *  Pulled on demand by the `get-electron-log-tail` IPC for diagnostic
  *  exports.  Independent of the `engine-log` IPC broadcast (which is
@@ -106,11 +227,12 @@ const _recentLogs: LogRecord[] = []
 
 /** Append a record to the rolling buffer, dropping the oldest entry
  *  once the cap is hit so the buffer stays bounded across long
- *  sessions.  Called from `emit` automatically; the uv-sync line
- *  stream calls it explicitly. */
+ *  sessions, and mirror it into the persistent log file.  Called from
+ *  `emit` automatically; the uv-sync line stream calls it explicitly. */
 export function recordElectronLog(record: LogRecord): void {
   _recentLogs.push(record)
   if (_recentLogs.length > RECENT_LOGS_MAX) _recentLogs.shift()
+  appendToLogFile(record)
 }
 
 /** Snapshot of the current rolling buffer.  Returns a copy so the
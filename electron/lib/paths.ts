@@ -13,7 +13,11 @@ const WORLD_ENGINE_DIR = 'world_engine'
  *  the engine: `.safety_cache.json` (NSFW classifier), `gemlite_config.json`
  *  (gemlite kernel cache), `.cache/` (Triton et al).  Note: `uv.lock` is
  *  intentionally *not* excluded — it's the canonical lockfile and must
- *  ride along with `pyproject.toml` so `uv sync` doesn't re-resolve. */
+ *  ride along with `pyproject.toml` so `uv sync` doesn't re-resolve.
+ *  `INSTALLED_UV_LOCK` is the copy of it taken after the last successful
+ *  sync, kept so the dependency audit can diff against it. */
+export const INSTALLED_UV_LOCK = '.installed-uv.lock'
+
 export const SERVER_COMPONENT_EXCLUDES = new Set([
   '.venv',
   '__pycache__',
@@ -24,13 +28,25 @@ export const SERVER_COMPONENT_EXCLUDES = new Set([
   '.safety_cache.json',
   'gemlite_config.json',
   '.python-version',
-  'node_modules'
+  'node_modules',
+  INSTALLED_UV_LOCK
 ])
 
+let dataDirOverride: string | null = null
+
+/** Point the portable data directory somewhere else (headless `--data-dir`).
+ *  Must be called before anything resolves engine or uv paths. */
+export function setDataDirOverride(dir: string): void {
+  dataDirOverride = path.resolve(dir)
+}
+
 /** Get the portable data directory.
  * AppImages are read-only squashfs mounts, so we use the directory
  * containing the .AppImage file itself instead. */
 export function getExeDir(): string {
+  if (dataDirOverride) {
+    return dataDirOverride
+  }
   if (process.env.APPIMAGE) {
     return path.dirname(process.env.APPIMAGE)
   }
@@ -67,6 +83,16 @@ export function getSeedsThumbnailDir(): string {
   return path.join(getConfigDir(), 'seeds', 'thumbnails')
 }
 
+/** Get the screenshots directory — in user config dir */
+export function getScreenshotsDir(): string {
+  return path.join(getConfigDir(), 'screenshots')
+}
+
+/** Get the crash reports directory — in user config dir */
+export function getCrashReportsDir(): string {
+  return path.join(getConfigDir(), 'crash_reports')
+}
+
 /** Get the config directory (uses Electron's userData) */
 export function getConfigDir(): string {
   return app.getPath('userData')
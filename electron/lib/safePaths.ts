@@ -0,0 +1,31 @@
+import path from 'node:path'
+
+// Path checks for IPC arguments. The renderer is treated as untrusted:
+// filenames it sends are resolved inside a directory main picked, never
+// joined blindly.
+
+/** True when `child` is strictly inside `parent` (not `parent` itself). */
+export function isWithin(child: string, parent: string): boolean {
+  const rel = path.relative(path.resolve(parent), path.resolve(child))
+  return rel !== '' && !rel.startsWith('..') && !path.isAbsolute(rel)
+}
+
+/** Whether `name` is a plain filename: no separators (either platform's),
+ *  no `.` / `..`, no NUL, not hidden. */
+export function isPlainFilename(name: string): boolean {
+  return (
+    typeof name === 'string' &&
+    name.length > 0 &&
+    !name.startsWith('.') &&
+    !/[/\\\0]/.test(name) &&
+    path.basename(name) === name
+  )
+}
+
+/** Resolve `name` as a direct child of `dir`, or null if it isn't a plain
+ *  filename. */
+export function resolveInside(dir: string, name: string): string | null {
+  if (!isPlainFilename(name)) return null
+  const resolved = path.join(dir, name)
+  return isWithin(resolved, dir) ? resolved : null
+}
@@ -16,6 +16,16 @@ const state: ServerState = {
   ready: false
 }
 
+// Processes we've asked to stop. The exit handler checks this so a
+// deliberate stop isn't reported as a crash: on Windows `taskkill /F`
+// exits the tree with code 1, which looks identical to a Python crash.
+// Tracked per process because `clearServerState` runs before `exit` fires.
+const intentionalStops = new WeakSet<ChildProcess>()
+
+export function wasStoppedIntentionally(proc: ChildProcess): boolean {
+  return intentionalStops.has(proc)
+}
+
 export function getServerState(): ServerState {
   return state
 }
@@ -43,6 +53,7 @@ export function stopServerSync(): string | null {
   }
 
   const pid = state.process.pid
+  intentionalStops.add(state.process)
   log.info('Stopping server process tree', { fields: { pid: pid ?? -1 } })
 
   if (pid) {
@@ -90,6 +101,7 @@ export async function stopServer({ gracePeriodMs = 10000 }: { gracePeriodMs?: nu
     return null
   }
   const pid = proc.pid
+  intentionalStops.add(proc)
 
   // If the process is already dead but `clearServerState` hasn't been
   // invoked yet (e.g. exit handler not yet fired), short-circuit.
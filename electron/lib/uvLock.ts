@@ -0,0 +1,150 @@
+import path from 'node:path'
+import type { LockedPackage, LockedPackageChange } from '../../src/types/ipc.js'
+
+// Just enough of uv.lock to audit it. uv writes the file in a fixed layout
+// (one `[[package]]` table per package, `key = value` on their own lines,
+// one wheel per line), so a line scan is reliable and avoids a TOML
+// dependency for a read-only report.
+
+export type ParsedLockPackage = LockedPackage & {
+  wheels: { filename: string; size: number | null }[]
+}
+
+export type ParsedLock = {
+  /** `requires-python`, e.g. `==3.12.*`. */
+  requiresPython: string | null
+  packages: ParsedLockPackage[]
+}
+
+const PYPI_REGISTRY = 'https://pypi.org/simple'
+
+export function parseUvLock(text: string): ParsedLock {
+  const packages: ParsedLockPackage[] = []
+  let requiresPython: string | null = null
+  let current: ParsedLockPackage | null = null
+  // True only in the top-level keys of a `[[package]]` table, not its
+  // `[package.metadata]` / `[package.optional-dependencies]` subtables.
+  let inPackage = false
+  let inWheels = false
+
+  for (const line of text.split(/\r?\n/)) {
+    if (line === '[[package]]') {
+      current = { name: '', version: '', source_kind: '', source: '', wheels: [] }
+      packages.push(current)
+      inPackage = true
+      inWheels = false
+      continue
+    }
+    if (line.startsWith('[')) {
+      inPackage = false
+      inWheels = false
+      continue
+    }
+
+    if (!current || !inPackage) {
+      const requires = /^requires-python = "([^"]*)"/.exec(line)
+      if (requires && !current) requiresPython = requires[1]
+      continue
+    }
+
+    if (inWheels) {
+      if (line === ']') {
+        inWheels = false
+        continue
+      }
+      const location = /(?:url|path|filename) = "([^"]+)"/.exec(line)
+      if (!location) continue
+      const size = /size = (\d+)/.exec(line)
+      current.wheels.push({
+        filename: path.posix.basename(location[1].split('#')[0]),
+        size: size ? Number(size[1]) : null
+      })
+      continue
+    }
+
+    const field = /^(name|version) = "([^"]*)"/.exec(line)
+    if (field) {
+      current[field[1] as 'name' | 'version'] = field[2]
+      continue
+    }
+    const source = /^source = \{ ([\w-]+) = "([^"]*)"/.exec(line)
+    if (source) {
+      current.source_kind = source[1]
+      current.source = source[2]
+      continue
+    }
+    if (/^wheels = \[$/.test(line)) inWheels = true
+  }
+
+  return { requiresPython, packages }
+}
+
+/** Packages fetched from somewhere other than PyPI. The project itself
+ *  (`editable` / `virtual`) is local and isn't reported. */
+export function isNonPypiSource(pkg: LockedPackage): boolean {
+  if (pkg.source_kind === 'editable' || pkg.source_kind === 'virtual') return false
+  return !(pkg.source_kind === 'registry' && pkg.source.replace(/\/$/, '') === PYPI_REGISTRY)
+}
+
+function platformTagMatches(tag: string): boolean {
+  if (tag === 'any') return true
+  const x64 = process.arch === 'x64'
+  switch (process.platform) {
+    case 'win32':
+      return tag === (x64 ? 'win_amd64' : 'win_arm64')
+    case 'darwin':
+      return tag.startsWith('macosx') && (tag.endsWith(x64 ? 'x86_64' : 'arm64') || tag.endsWith('universal2'))
+    default:
+      return tag.includes('linux') && tag.endsWith(x64 ? 'x86_64' : 'aarch64')
+  }
+}
+
+/** Whether a wheel installs on this machine, from its filename tags
+ *  (`{name}-{version}(-{build})?-{python}-{abi}-{platform}.whl`). `python`
+ *  is the lock's interpreter as e.g. `312`, or null to accept any. */
+export function wheelMatchesPlatform(filename: string, python: string | null): boolean {
+  const parts = filename.replace(/\.whl$/, '').split('-')
+  if (parts.length < 5) return false
+  const [pythonTags, abi, platformTags] = parts.slice(-3).map((tags) => tags.split('.'))
+  const pythonOk =
+    python === null ||
+    abi.includes('abi3') ||
+    pythonTags.some((tag) => tag === `cp${python}` || tag === `py${python}` || tag === 'py3' || tag === 'py2')
+  return pythonOk && platformTags.some(platformTagMatches)
+}
+
+/** Compare two locks by package name. A name locked at several versions
+ *  (per-platform variants) is compared as its sorted version list. */
+export function diffLockedPackages(
+  before: LockedPackage[],
+  after: LockedPackage[]
+): { added: LockedPackage[]; removed: LockedPackage[]; changed: LockedPackageChange[] } {
+  const group = (packages: LockedPackage[]) => {
+    const byName = new Map<string, LockedPackage[]>()
+    for (const pkg of packages) byName.set(pkg.name, [...(byName.get(pkg.name) ?? []), pkg])
+    return byName
+  }
+  const versions = (packages: LockedPackage[]) =>
+    packages
+      .map((pkg) => pkg.version)
+      .sort()
+      .join(', ')
+
+  const beforeByName = group(before)
+  const afterByName = group(after)
+  const added: LockedPackage[] = []
+  const removed: LockedPackage[] = []
+  const changed: LockedPackageChange[] = []
+
+  for (const [name, packages] of afterByName) {
+    const previous = beforeByName.get(name)
+    if (!previous) added.push(...packages)
+    else if (versions(previous) !== versions(packages)) {
+      changed.push({ name, from: versions(previous), to: versions(packages) })
+    }
+  }
+  for (const [name, packages] of beforeByName) {
+    if (!afterByName.has(name)) removed.push(...packages)
+  }
+  return { added, removed, changed }
+}
@@ -6,10 +6,19 @@ export async function runUvSyncWithMirroredLogs(
   uvBinary: string,
   cwd: string,
   env: NodeJS.ProcessEnv,
-  options?: { signal?: AbortSignal; onLine?: (line: string, isStderr: boolean) => void }
+  options?: {
+    signal?: AbortSignal
+    onLine?: (line: string, isStderr: boolean) => void
+    /** Pass `--reinstall`, rewriting every package in the venv from the
+     *  cache. Plain `sync` trusts installed dist-info and won't notice
+     *  files damaged after install (e.g. by a power loss). */
+    reinstall?: boolean
+  }
 ): Promise<void> {
   const signal = options?.signal
   const onLine = options?.onLine
+  const args = ['sync', '--verbose', '--index-strategy', 'unsafe-best-match']
+  if (options?.reinstall) args.push('--reinstall')
 
   await new Promise<void>((resolve, reject) => {
     if (signal?.aborted) {
@@ -17,7 +26,7 @@ export async function runUvSyncWithMirroredLogs(
       return
     }
 
-    const child = spawn(uvBinary, ['sync', '--verbose', '--index-strategy', 'unsafe-best-match'], {
+    const child = spawn(uvBinary, args, {
       cwd,
       env,
       stdio: ['ignore', 'pipe', 'pipe'],
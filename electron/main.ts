@@ -5,10 +5,64 @@ import { registerAllIpc } from './ipc/index.js'
 import { getServerState, stopServer, stopServerSync } from './lib/serverState.js'
 import { getBackgroundsDir } from './ipc/backgrounds.js'
 import { getCurrentRecordingsDir } from './ipc/recordings.js'
-import { getLogger } from './lib/logger.js'
+import { flushLogFileSync, getLogger } from './lib/logger.js'
+import { startCrashReporter, writeCrashReport } from './lib/crashReports.js'
+import { parseHeadlessArgs, runHeadless, type HeadlessArgs } from './headless.js'
+import { findDeepLinkArg, handleDeepLink, queueDeepLink, registerDeepLinkProtocol } from './ipc/deepLink.js'
+import { createTray } from './ipc/tray.js'
+import { checkServerUrl, findServerArg, readSettingsSync } from './ipc/settings.js'
+import { DEFAULT_WINDOW_SIZE, loadWindowState, trackWindowState } from './ipc/window.js'
+import { startMaintenanceSchedule } from './ipc/maintenance.js'
 
 const log = getLogger('electron.main')
 
+startCrashReporter()
+
+// `--headless setup|status` runs engine setup without opening a window
+// (CI, provisioning scripts). Parsed up front so a bad invocation fails
+// before Electron spins anything up.
+let headlessArgs: HeadlessArgs | null = null
+try {
+  headlessArgs = parseHeadlessArgs(process.argv)
+} catch (err) {
+  process.stderr.write(`${err instanceof Error ? err.message : String(err)}\n`)
+  process.exit(2)
+}
+
+// One windowed instance owns `biome://` links: a second launch (Windows /
+// Linux deliver links that way) hands its argv over and exits.
+if (!headlessArgs && !app.requestSingleInstanceLock()) {
+  app.exit(0)
+}
+
+app.on('second-instance', (_event, argv) => {
+  const link = findDeepLinkArg(argv)
+  if (link) handleDeepLink(link)
+  // Overrides are read once at startup, so a later `--server` can't apply
+  // silently; offer it through the same confirm prompt as a connect link.
+  const serverUrl = findServerArg(argv)?.trim()
+  if (serverUrl) {
+    const issue = checkServerUrl(serverUrl)
+    if (issue) {
+      log.warning('Ignoring --server from second launch', { fields: { url: serverUrl, reason: issue.message } })
+    } else {
+      log.info('Forwarding --server from second launch')
+      queueDeepLink({ kind: 'connect', server_url: serverUrl })
+    }
+  }
+  if (mainWindow) {
+    if (mainWindow.isMinimized()) mainWindow.restore()
+    mainWindow.show()
+    mainWindow.focus()
+  }
+})
+
+// macOS delivers links through `open-url`, possibly before `ready`.
+app.on('open-url', (event, url) => {
+  event.preventDefault()
+  handleDeepLink(url)
+})
+
 // Register biome-bg / biome-recording as privileged schemes so <video> elements
 // can stream from them. Must be called before app.whenReady().
 protocol.registerSchemesAsPrivileged([
@@ -26,6 +80,9 @@ declare const MAIN_WINDOW_VITE_DEV_SERVER_URL: string | undefined
 declare const MAIN_WINDOW_VITE_NAME: string
 
 let mainWindow: BrowserWindow | null = null
+// Set once a real quit starts, so the minimize-to-tray close handler
+// lets the window go.
+let isQuitting = false
 
 const resolveWindowIcon = (): string | Electron.NativeImage | undefined => {
   const icoPath = path.join(__dirname, '../../app-icon.ico')
@@ -36,14 +93,14 @@ const resolveWindowIcon = (): string | Electron.NativeImage | undefined => {
 
 const createWindow = () => {
   const icon = resolveWindowIcon()
+  const windowState = loadWindowState()
   mainWindow = new BrowserWindow({
-    width: 1280,
-    height: 720,
+    ...(windowState?.bounds ?? DEFAULT_WINDOW_SIZE),
     minWidth: 800,
     minHeight: 450,
     maximizable: false,
     resizable: true,
-    center: true,
+    center: !windowState,
     frame: false,
     show: false,
     backgroundColor: '#000000',
@@ -59,6 +116,7 @@ const createWindow = () => {
   // Enforce a fixed 16:9 window aspect ratio natively.
   // Replaces old useFitWindowToContent()
   mainWindow.setAspectRatio(16 / 9)
+  trackWindowState(mainWindow, windowState)
 
   if (MAIN_WINDOW_VITE_DEV_SERVER_URL) {
     mainWindow.loadURL(MAIN_WINDOW_VITE_DEV_SERVER_URL)
@@ -117,6 +175,14 @@ const createWindow = () => {
     }
   }, 5000)
 
+  // With `minimize_to_tray`, closing only hides the window: the renderer
+  // (and the engine lifecycle it owns) keeps running behind the tray icon.
+  mainWindow.on('close', (event) => {
+    if (isQuitting || !readSettingsSync().minimize_to_tray) return
+    event.preventDefault()
+    mainWindow?.hide()
+  })
+
   mainWindow.on('closed', () => {
     clearTimeout(showFallbackTimer)
     mainWindow = null
@@ -126,6 +192,13 @@ const createWindow = () => {
 app
   .whenReady()
   .then(async () => {
+    if (headlessArgs) {
+      const exitCode = await runHeadless(headlessArgs)
+    flushLogFileSync()
+    app.exit(exitCode)
+      return
+    }
+
     protocol.handle('biome-bg', (request) => {
       const url = new URL(request.url)
       // With standard scheme, biome-bg://serve/autumn.mp4 → hostname=serve, pathname=/autumn.mp4
@@ -166,13 +239,33 @@ app
     })
 
     registerAllIpc()
+    registerDeepLinkProtocol()
     createWindow()
+    createTray(resolveWindowIcon())
+    startMaintenanceSchedule()
+
+    const launchLink = findDeepLinkArg(process.argv)
+    if (launchLink) handleDeepLink(launchLink)
   })
   .catch((err) => {
     log.error('Startup failed', { exception: err instanceof Error ? (err.stack ?? err.message) : String(err) })
     app.quit()
   })
 
+// A dead renderer leaves a frozen window with no JS to report anything, so
+// capture the reason here; the minidump (if any) comes from crashReporter.
+app.on('render-process-gone', (_event, _webContents, details) => {
+  log.error('Renderer process gone', { fields: { reason: details.reason, exit_code: details.exitCode } })
+  writeCrashReport('renderer-gone', { reason: details.reason, exit_code: details.exitCode })
+})
+
+app.on('child-process-gone', (_event, details) => {
+  if (details.reason === 'clean-exit') return
+  const fields = { type: details.type, reason: details.reason, exit_code: details.exitCode }
+  log.error('Child process gone', { fields })
+  writeCrashReport('child-process-gone', fields)
+})
+
 app.on('window-all-closed', () => {
   if (process.platform !== 'darwin') {
     app.quit()
@@ -180,11 +273,18 @@ app.on('window-all-closed', () => {
 })
 
 app.on('activate', () => {
+  if (headlessArgs) return
   if (BrowserWindow.getAllWindows().length === 0) {
     createWindow()
+  } else if (mainWindow && !mainWindow.isVisible()) {
+    mainWindow.show()
   }
 })
 
+// Anything logged during shutdown would otherwise wait for a tick that
+// never comes.
+app.on('will-quit', () => flushLogFileSync())
+
 // `before-quit` re-fires after we call `app.quit()` (and may re-fire if the
 // user triggers quit again while shutdown is in flight). `complete` lets the
 // final pass through unchanged so Electron actually tears the window down;
@@ -194,6 +294,7 @@ let serverShutdownComplete = false
 let serverShutdownInFlight = false
 
 app.on('before-quit', (event) => {
+  isQuitting = true
   if (serverShutdownComplete) return
   if (!getServerState().process) return
   if (serverShutdownInFlight) {
@@ -217,22 +318,29 @@ const signalGracePeriodMs = 1500
 
 process.on('SIGINT', () => {
   log.info('Received SIGINT, stopping server')
-  void stopServer({ gracePeriodMs: signalGracePeriodMs }).finally(() => process.exit(0))
+  void stopServer({ gracePeriodMs: signalGracePeriodMs }).finally(() => {
+    flushLogFileSync()
+    process.exit(0)
+  })
 })
 
 process.on('SIGTERM', () => {
   log.info('Received SIGTERM, stopping server')
-  void stopServer({ gracePeriodMs: signalGracePeriodMs }).finally(() => process.exit(0))
+  void stopServer({ gracePeriodMs: signalGracePeriodMs }).finally(() => {
+    flushLogFileSync()
+    process.exit(0)
+  })
 })
 
 // uncaughtException leaves the event loop in an undefined state — async I/O
 // (including the graceful HTTP request) can't be trusted to make progress.
 // Sync force-kill is the only safe path here.
 process.on('uncaughtException', (err) => {
-  log.error('Uncaught exception, stopping server', {
-    exception: err instanceof Error ? (err.stack ?? err.message) : String(err)
-  })
+  const exception = err instanceof Error ? (err.stack ?? err.message) : String(err)
+  log.error('Uncaught exception, stopping server', { exception })
+  writeCrashReport('main-exception', { exception })
   stopServerSync()
+  flushLogFileSync()
   process.exit(1)
 })
 
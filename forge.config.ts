@@ -15,6 +15,11 @@ const config: ForgeConfig = {
     executableName: 'biome',
     icon: './app-icon',
     appCopyright: 'Copyright © 2026 Overworld',
+    // macOS reads URL schemes from Info.plist. Windows registers at runtime
+    // via app.setAsDefaultProtocolClient; Linux writes its own desktop entry
+    // at runtime (electron/ipc/deepLink.ts), and the AppImage's entry also
+    // declares the scheme for AppImage integration tools.
+    protocols: [{ name: 'Biome', schemes: ['biome'] }],
     extraResource: [
       './server-components',
       './seeds',
@@ -290,6 +290,7 @@ function fixupDesktopEntry(appDir) {
   const insertions = []
   if (!hasKey('Categories')) insertions.push('Categories=Game;')
   if (!hasKey('Icon')) insertions.push('Icon=biome')
+  if (!hasKey('MimeType')) insertions.push('MimeType=x-scheme-handler/biome;')
 
   if (insertions.length) {
     const out = []
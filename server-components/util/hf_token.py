@@ -3,9 +3,10 @@ HuggingFace token resolution.
 
 Biome overrides `HF_HOME` to keep the model cache inside `world_engine/`,
 which means `huggingface_hub` won't find the user's default token at
-`~/.cache/huggingface/token`. `apply_resolved_token()` re-implements the
-Electron-side `getHfToken()` resolution order so both paths find the
-same token regardless of `HF_HOME` overrides.
+`~/.cache/huggingface/token`. `apply_resolved_token()` walks the usual
+env vars and well-known token files so the user's token is still found
+regardless of `HF_HOME` overrides. A token saved in Biome's settings
+arrives as `HF_TOKEN`, set by Electron at spawn.
 
 Call `apply_resolved_token()` once at startup, before any module that
 reads `HF_TOKEN` is imported.
@@ -21,8 +22,7 @@ logger = structlog.stdlib.get_logger(__name__)
 
 def resolve_hf_token() -> str | None:
     """Resolve a HuggingFace token from env vars and well-known file
-    locations, in the same order the Electron-side `getHfToken()` uses.
-    Returns None if no token is found."""
+    locations. Returns None if no token is found."""
     # 1. HF_TOKEN env var
     token = os.environ.get("HF_TOKEN")
     if token:
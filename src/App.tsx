@@ -6,14 +6,17 @@ import { usePortal } from './context/portal/portalContextValue'
 import { StreamingProvider } from './context/streaming/StreamingContext'
 import { useConnection } from './context/streaming/connection'
 import { useSession } from './context/streaming/session'
+import { useSeeds } from './context/streaming/seeds'
+import { useSettings } from './hooks/settings/settingsContextValue'
 import { VortexProvider } from './context/vortex/VortexContext'
 import { AudioProvider } from './context/audio/AudioContext'
 import { useAudio } from './context/audio/audioContextValue'
 import AudioController from './components/audio/AudioController'
 import { EngineLifecycleProvider } from './context/engineLifecycle/EngineLifecycleContext'
-import { invoke } from './bridge'
-import type { AppUpdateInfo } from './types/ipc'
+import { invoke, listen } from './bridge'
+import type { AppUpdateInfo, DeepLink } from './types/ipc'
 import VideoContainer from './components/streaming/VideoContainer'
+import ScreenshotToast from './components/streaming/ScreenshotToast'
 import MenuSettingsView from './components/settings/MenuSettingsView'
 import BackgroundSlideshow from './components/menu/BackgroundSlideshow'
 import PortalPreview from './components/menu/PortalPreview'
@@ -79,6 +82,10 @@ const AppShell = () => {
   const { play, startLoop, fadeOutLoop } = useAudio()
   const [transitionPhase, setTransitionPhase] = useState<TransitionPhase>('idle')
   const [availableUpdate, setAvailableUpdate] = useState<AppUpdateInfo | null>(null)
+  const [pendingDeepLink, setPendingDeepLink] = useState<DeepLink | null>(null)
+  // Seed from a confirmed `biome://world` link, applied once the session
+  // is streaming (the bootstrap always starts from the default seed).
+  const deepLinkSeedRef = useRef<string | null>(null)
   const [editPromptVisible, setEditPromptVisible] = useState(false)
   const [editPreviewVisible, setEditPreviewVisible] = useState(false)
   const editPromptKeyRef = useRef(0)
@@ -99,6 +106,8 @@ const AppShell = () => {
   } = usePortal()
   const { isStreaming, isUIActive, status: connectionStatus, prepareReturnToMainMenu } = useConnection()
   const sceneEditState = useSession().sceneEdit.state
+  const selectSeed = useSeeds().select
+  const { settings, saveSettings } = useSettings()
   useGamepadNavigation(isUIActive)
   const {
     getBackgroundVideoElement,
@@ -177,12 +186,46 @@ const AppShell = () => {
     }
 
     void checkForUpdate()
+    // Main re-checks periodically and pushes releases newer than this one.
+    const unlisten = listen('app-update-available', setAvailableUpdate)
 
     return () => {
       cancelled = true
+      unlisten()
     }
   }, [])
 
+  // `biome://` links: collect whatever launched the app, then re-check on
+  // every nudge from main. Taking (rather than reading the event payload)
+  // keeps a link from being handled twice.
+  useEffect(() => {
+    const takePending = () => {
+      invoke('take-pending-deep-link')
+        .then((link) => link && setPendingDeepLink(link))
+        .catch((error) => console.warn('[DEEP_LINK] Failed to read pending link:', error))
+    }
+    takePending()
+    return listen('deep-link', takePending)
+  }, [])
+
+  useEffect(() => {
+    if (!isStreamingUi || !deepLinkSeedRef.current) return
+    const seed = deepLinkSeedRef.current
+    deepLinkSeedRef.current = null
+    selectSeed(seed).catch((error) => console.warn('[DEEP_LINK] Failed to load seed:', error))
+  }, [isStreamingUi, selectSeed])
+
+  // Session mode for the length of a stream: fullscreen and no display
+  // sleep. Main restores the window if it closes mid-session.
+  const sessionFullscreen = settings.display.session_fullscreen
+  useEffect(() => {
+    if (!isStreamingUi || !sessionFullscreen) return
+    invoke('enter-session-mode').catch((error) => console.warn('[WINDOW] Failed to enter session mode:', error))
+    return () => {
+      invoke('exit-session-mode').catch(() => null)
+    }
+  }, [isStreamingUi, sessionFullscreen])
+
   // Arm the streaming-reveal phase synchronously when isStreamingUi flips on,
   // so the loading layer's rendering condition (which depends on the phase)
   // doesn't see a one-render gap between `isLoadingUi` going false and the
@@ -268,6 +311,23 @@ const AppShell = () => {
     }
   }
 
+  const handleConfirmDeepLink = async (link: DeepLink) => {
+    setPendingDeepLink(null)
+    if (link.kind === 'connect') {
+      // A running session picks the change up through the normal
+      // settings-diff respawn; from the menu, launch straight in.
+      await saveSettings({ ...settings, engine_mode: 'server', server_url: link.server_url })
+    } else if (isStreamingUi) {
+      await selectSeed(link.seed)
+      return
+    } else {
+      deepLinkSeedRef.current = link.seed
+    }
+    // Settings blocks the launch; closing it leaves the portal one click away.
+    if (isSettingsOpen) toggleSettings()
+    else handleLaunch()
+  }
+
   const handleCancelLoading = () => {
     if (transitionPhase === 'return-to-menu' || portalState !== portalStates.LOADING) return
     play('portal_swoosh_long')
@@ -415,6 +475,7 @@ const AppShell = () => {
             <div className="pointer-events-none absolute z-2" id="logo-container"></div>
             <PauseOverlay />
             <SceneEditOverlay />
+            <ScreenshotToast />
             {SCENE_EDIT_DEBUG_PREVIEW &&
               editPromptVisible &&
               sceneEditState.lastEditPrompt &&
@@ -491,7 +552,25 @@ const AppShell = () => {
       </div>
       {PORTAL_SPARKS_DEBUG && <PortalSparksConfigurator />}
       <FocusReticle />
-      {availableUpdate && (
+      {pendingDeepLink && (
+        <ConfirmModal
+          title={
+            pendingDeepLink.kind === 'connect' ? 'app.dialogs.deepLinkConnect.title' : 'app.dialogs.deepLinkWorld.title'
+          }
+          description={
+            pendingDeepLink.kind === 'connect'
+              ? 'app.dialogs.deepLinkConnect.description'
+              : 'app.dialogs.deepLinkWorld.description'
+          }
+          descriptionParams={
+            pendingDeepLink.kind === 'connect' ? { url: pendingDeepLink.server_url } : { seed: pendingDeepLink.seed }
+          }
+          onCancel={() => setPendingDeepLink(null)}
+          onConfirm={() => void handleConfirmDeepLink(pendingDeepLink)}
+          confirmLabel="app.buttons.launch"
+        />
+      )}
+      {availableUpdate && !isStreamingUi && (
         <ConfirmModal
           title="app.dialogs.updateAvailable.title"
           description="app.dialogs.updateAvailable.description"
@@ -5,6 +5,7 @@ import type { DiagnosticsPayload, LogRecord } from '../../types/ipc'
 import Button from '../ui/Button'
 import { findFocusables, findInDirection, focusSmooth } from '../../lib/focusNavigation'
 import { getActiveScopeRoot } from '../../context/focus/focusScopeStack'
+import { formatLogRecordPlainText } from '../../lib/logRecordText'
 
 const MAX_ERROR_MESSAGE_CHARS = 220
 const MAX_GITHUB_BODY_CHARS = 1200
@@ -25,25 +26,6 @@ const LEVEL_TEXT_CLASS: Record<string, string> = {
   critical: 'text-(--color-error-bright)'
 }
 
-/** Render a single `LogRecord` as a flat string for places the renderer
- *  can't markup — clipboard exports, GitHub-issue body, etc.  Layout
- *  matches the Python / Electron text renderers: `timestamp [level]
- *  [logger] event k=v ...`, with the logger pill before the event so the
- *  fixed prefix block stays scannable. */
-function formatLogRecordPlainText(record: LogRecord): string {
-  const parts: string[] = []
-  if (record.timestamp) parts.push(record.timestamp)
-  if (record.level) parts.push(`[${record.level}]`)
-  if (record.logger) parts.push(`[${record.logger}]`)
-  parts.push(record.event)
-  if (record.fields) {
-    for (const [k, v] of Object.entries(record.fields)) parts.push(`${k}=${String(v)}`)
-  }
-  let line = parts.join(' ')
-  if (record.exception) line += `\n${record.exception}`
-  return line
-}
-
 /** Pretty-render a single `LogRecord` in the on-screen log panel.
  *  Visual hierarchy mirrors the plain-text formatter — timestamp dim,
  *  level uppercase + color-coded, logger pill subdued, event prominent,
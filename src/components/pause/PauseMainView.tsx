@@ -21,6 +21,7 @@ interface PauseMainViewProps {
   onRemoveScene: (seed: SeedRecord) => void
   onMoveScene: (filename: string, targetIdx: number) => void
   onNavigateSettings: () => void
+  onOpenSavedWorlds: () => void
   onImageUpload: (event: ChangeEvent<HTMLInputElement>) => void
   onImageDrop: (files: File[]) => void
   requestPointerLock: () => void
@@ -40,6 +41,7 @@ const PauseMainView = ({
   onRemoveScene,
   onMoveScene,
   onNavigateSettings,
+  onOpenSavedWorlds,
   onImageUpload,
   onImageDrop,
   requestPointerLock,
@@ -214,6 +216,12 @@ const PauseMainView = ({
       )}
 
       <div className="absolute right-(--edge-right) bottom-(--edge-bottom) flex gap-[1.1cqh]">
+        <MenuButton
+          variant="secondary"
+          label="app.buttons.savedWorlds"
+          onClick={onOpenSavedWorlds}
+          disabled={isGenerating}
+        />
         <MenuButton
           variant="secondary"
           label="app.buttons.settings"
@@ -6,6 +6,7 @@ import { useSession } from '../../context/streaming/session'
 import { useWebsocket } from '../../context/streaming/websocket'
 import MenuSettingsView from '../settings/MenuSettingsView'
 import PauseMainView from './PauseMainView'
+import SavedWorldsModal from './SavedWorldsModal'
 import { PAUSE_VIEW, type PauseViewKey } from '../../constants'
 import { viewFadeVariants } from '../../transitions'
 import { useSeedManager } from '../../hooks/seeds/useSeedManager'
@@ -24,6 +25,7 @@ const PauseOverlayContent = () => {
   const { settings } = useSettings()
   const pauseMenuCode = settings.keybindings.pauseMenu
   const [view, setView] = useState<PauseViewKey>(PAUSE_VIEW.MAIN)
+  const [showSavedWorlds, setShowSavedWorlds] = useState(false)
   const { selectCooldown } = usePointerLockFeedback(true)
   /** Filename of the most recently-added scene (via prompt OR upload/paste/drop).
    *  Drives auto-scroll and the "unpause to play" hint in PauseMainView. */
@@ -82,17 +84,22 @@ const PauseOverlayContent = () => {
       if (e.key !== 'Escape' && e.code !== pauseMenuCode) return
       // Settings view handles its own Escape (to save draft settings before navigating)
       if (view === PAUSE_VIEW.SETTINGS) return
+      // Escape backs out of the saved worlds modal rather than resuming
+      if (showSavedWorlds) {
+        if (e.key === 'Escape') setShowSavedWorlds(false)
+        return
+      }
       if (isGenerating) return
       requestPointerLock()
     }
 
     window.addEventListener('keyup', handleKeyUp)
     return () => window.removeEventListener('keyup', handleKeyUp)
-  }, [view, isGenerating, requestPointerLock, pauseMenuCode])
+  }, [view, showSavedWorlds, isGenerating, requestPointerLock, pauseMenuCode])
 
   return (
     <FocusScope
-      active={view !== PAUSE_VIEW.SETTINGS}
+      active={view !== PAUSE_VIEW.SETTINGS && !showSavedWorlds}
       autoFocus
       onCancel={requestPointerLock}
       className="pointer-events-auto absolute inset-0 z-45 bg-black/34 backdrop-blur-[1.94cqh]"
@@ -129,6 +136,7 @@ const PauseOverlayContent = () => {
               onRemoveScene={removeSceneFile}
               onMoveScene={moveScene}
               onNavigateSettings={() => setView(PAUSE_VIEW.SETTINGS)}
+              onOpenSavedWorlds={() => setShowSavedWorlds(true)}
               onImageUpload={handleImageUpload}
               onImageDrop={handleImageDrop}
               requestPointerLock={requestPointerLock}
@@ -140,6 +148,9 @@ const PauseOverlayContent = () => {
           </motion.div>
         )}
       </AnimatePresence>
+      {showSavedWorlds && (
+        <SavedWorldsModal seeds={seeds} seedThumbnails={thumbnails} onClose={() => setShowSavedWorlds(false)} />
+      )}
     </FocusScope>
   )
 }
@@ -0,0 +1,346 @@
+import { useCallback, useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import { invoke } from '../../bridge'
+import { useInput } from '../../context/streaming/input'
+import { useSeeds } from '../../context/streaming/seeds'
+import { useStreamingSurface } from '../../context/streaming/surface'
+import { useSettings } from '../../hooks/settings/settingsContextValue'
+import type { SeedRecord } from '../../types/app'
+import type { SessionDraft, SessionRecord } from '../../types/sessions'
+import { SETTINGS_MUTED_TEXT, SETTINGS_MUTED_TEXT_WITHOUT_FONT_SIZE } from '../../styles'
+import { createLogger } from '../../utils/logger'
+import { formatDate } from '../../utils/formatDate'
+import Modal from '../ui/Modal'
+import ConfirmModal from '../ui/ConfirmModal'
+import Button from '../ui/Button'
+
+const log = createLogger('SavedWorlds')
+
+type SavedWorldsModalProps = {
+  /** Seed listing from the pause menu — resolves the current seed's source
+   *  and supplies fallback thumbnails for sessions saved without one. */
+  seeds: SeedRecord[]
+  seedThumbnails: Record<string, string>
+  onClose: () => void
+}
+
+/** Strip any `org/` prefix from a model URI for compact display. */
+const shortModelName = (model: string): string => model.slice(model.lastIndexOf('/') + 1)
+
+const seedStem = (filename: string): string => filename.replace(/\.[^.]+$/, '')
+
+const SavedWorldsModal = ({ seeds, seedThumbnails, onClose }: SavedWorldsModalProps) => {
+  const { t, i18n } = useTranslation()
+  const { settings, saveSettings } = useSettings()
+  const { current: currentSeed, select: selectSeed } = useSeeds()
+  const { captureFrame } = useStreamingSurface()
+  const requestPointerLock = useInput().pointerLock.request
+
+  const [sessions, setSessions] = useState<SessionRecord[]>([])
+  const [thumbnails, setThumbnails] = useState<Record<string, string>>({})
+  const [loading, setLoading] = useState(true)
+  const [saving, setSaving] = useState(false)
+  // Translated message for the last failed action; export / import report
+  // their own reason, save / load fall back to the generic one.
+  const [error, setError] = useState<string | null>(null)
+  const [notice, setNotice] = useState<string | null>(null)
+  const [confirmDelete, setConfirmDelete] = useState<SessionRecord | null>(null)
+
+  const currentSeedRecord = seeds.find((s) => s.filename === currentSeed) ?? null
+
+  const refresh = useCallback(async () => {
+    setLoading(true)
+    try {
+      const list = await invoke('list-sessions')
+      setSessions(list)
+      const entries = await Promise.all(
+        list.map(async (s) => {
+          const thumb = await invoke('get-session-thumbnail-base64', s.id).catch(() => null)
+          return thumb ? ([s.id, `data:image/jpeg;base64,${thumb}`] as const) : null
+        })
+      )
+      setThumbnails(Object.fromEntries(entries.filter((e) => e !== null)))
+    } finally {
+      setLoading(false)
+    }
+  }, [])
+
+  useEffect(() => {
+    void refresh()
+  }, [refresh])
+
+  const handleSave = useCallback(async () => {
+    if (!currentSeedRecord) return
+    setSaving(true)
+    setError(null)
+    setNotice(null)
+    const draft: SessionDraft = {
+      seed_filename: currentSeedRecord.filename,
+      seed_source: currentSeedRecord.source,
+      prompt: null,
+      engine_model: settings.engine_model,
+      engine_backend: settings.engine_backend,
+      engine_mode: settings.engine_mode,
+      server_url: settings.engine_mode === 'server' ? settings.server_url : ''
+    }
+    try {
+      await invoke('save-session', draft, captureFrame() ?? undefined)
+      await refresh()
+    } catch (err) {
+      log.error('Failed to save world:', err)
+      setError(t('app.dialogs.savedWorlds.failed'))
+    } finally {
+      setSaving(false)
+    }
+  }, [currentSeedRecord, settings, captureFrame, refresh, t])
+
+  // Restores the scene plus the model/backend it ran with. Where the engine
+  // runs (standalone vs server) is left alone — a saved server may be gone.
+  const handleLoad = useCallback(
+    async (id: string) => {
+      setError(null)
+      setNotice(null)
+      try {
+        const record = await invoke('load-session', id)
+        if (!record) {
+          await refresh()
+          return
+        }
+        onClose()
+        await selectSeed(record.seed_filename)
+        const backend = record.engine_backend ?? settings.engine_backend
+        if (record.engine_model !== settings.engine_model || backend !== settings.engine_backend) {
+          // The settings diff reconnects the session, which re-bootstraps
+          // from the seed selected above.
+          await saveSettings({ ...settings, engine_model: record.engine_model, engine_backend: backend })
+          return
+        }
+        requestPointerLock()
+      } catch (err) {
+        log.error('Failed to load world:', err)
+        setError(t('app.dialogs.savedWorlds.failed'))
+      }
+    },
+    [settings, saveSettings, selectSeed, requestPointerLock, onClose, refresh, t]
+  )
+
+  const handleShare = useCallback(
+    async (id: string) => {
+      setError(null)
+      setNotice(null)
+      try {
+        const result = await invoke('export-world-file', id)
+        if (!result) return
+        if (result.ok) setNotice(t('app.dialogs.savedWorlds.shared', { path: result.file_path }))
+        else setError(result.message)
+      } catch (err) {
+        log.error('Failed to export world file:', err)
+        setError(t('app.dialogs.savedWorlds.failed'))
+      }
+    },
+    [t]
+  )
+
+  // A successful import also queues the world through the deep-link
+  // prompt, so the user is asked whether to jump straight in.
+  const handleImport = useCallback(async () => {
+    setError(null)
+    setNotice(null)
+    try {
+      const result = await invoke('import-world-file')
+      if (!result) return
+      if (result.ok) await refresh()
+      else setError(result.message)
+    } catch (err) {
+      log.error('Failed to import world file:', err)
+      setError(t('app.dialogs.savedWorlds.failed'))
+    }
+  }, [refresh, t])
+
+  const handleConfirmDelete = useCallback(async () => {
+    if (!confirmDelete) return
+    await invoke('delete-session', confirmDelete.id)
+    setConfirmDelete(null)
+    await refresh()
+  }, [confirmDelete, refresh])
+
+  const availableSeeds = new Set(seeds.map((s) => s.filename))
+
+  return (
+    <>
+      <Modal title="app.dialogs.savedWorlds.title" onCancel={onClose} onBackdropClick={onClose}>
+        <div className="flex items-center justify-between gap-[1cqh]">
+          <p
+            className={`
+              m-0
+              ${SETTINGS_MUTED_TEXT}
+              text-[2cqh]
+            `}
+          >
+            {t('app.dialogs.savedWorlds.description')}
+          </p>
+          <div className="flex shrink-0 gap-[0.8cqh]">
+            <Button
+              variant="secondary"
+              autoShrinkLabel
+              label="app.dialogs.savedWorlds.importFile"
+              className="px-[1.4cqh] py-[0.2cqh] text-[2cqh]"
+              onClick={() => void handleImport()}
+            />
+            <Button
+              variant="secondary"
+              autoShrinkLabel
+              label="app.dialogs.savedWorlds.saveCurrent"
+              className="px-[1.4cqh] py-[0.2cqh] text-[2cqh]"
+              disabled={!currentSeedRecord || saving}
+              onClick={() => void handleSave()}
+            />
+          </div>
+        </div>
+        {error && <p className="m-0 mt-[0.6cqh] font-serif text-caption text-error-bright">{error}</p>}
+        {notice && (
+          <p
+            className={`
+              m-0 mt-[0.6cqh] truncate
+              ${SETTINGS_MUTED_TEXT}
+            `}
+          >
+            {notice}
+          </p>
+        )}
+
+        <div className="styled-scrollbar mt-[1.4cqh] max-h-[52cqh] min-h-[20cqh] overflow-y-auto pr-[0.4cqh]">
+          {loading ? (
+            <p
+              className={`
+                m-[4cqh_0] text-center
+                ${SETTINGS_MUTED_TEXT}
+              `}
+            >
+              …
+            </p>
+          ) : sessions.length === 0 ? (
+            <p
+              className={`
+                m-[4cqh_0] text-center
+                ${SETTINGS_MUTED_TEXT}
+              `}
+            >
+              {t('app.dialogs.savedWorlds.empty')}
+            </p>
+          ) : (
+            <ul className="m-0 flex list-none flex-col gap-[0.8cqh] p-0">
+              {sessions.map((session) => (
+                <SavedWorldRow
+                  key={session.id}
+                  session={session}
+                  thumbnailSrc={thumbnails[session.id] ?? seedThumbnails[session.seed_filename]}
+                  seedAvailable={availableSeeds.has(session.seed_filename)}
+                  locale={i18n.language}
+                  onLoad={() => void handleLoad(session.id)}
+                  onShare={() => void handleShare(session.id)}
+                  onDelete={() => setConfirmDelete(session)}
+                />
+              ))}
+            </ul>
+          )}
+        </div>
+
+        <div className="mt-[1.4cqh] flex justify-end">
+          <Button
+            variant="primary"
+            autoShrinkLabel
+            label="app.buttons.close"
+            className="p-[0.5cqh_1.78cqh] text-[2.49cqh]"
+            onClick={onClose}
+          />
+        </div>
+      </Modal>
+
+      {confirmDelete && (
+        <ConfirmModal
+          title="app.dialogs.savedWorlds.confirmDeleteTitle"
+          description="app.dialogs.savedWorlds.confirmDeleteDescription"
+          descriptionParams={{ name: seedStem(confirmDelete.seed_filename) }}
+          descriptionComponents={{ bold: <span className="text-white" /> }}
+          onCancel={() => setConfirmDelete(null)}
+          onConfirm={() => void handleConfirmDelete()}
+          confirmLabel="app.buttons.delete"
+        />
+      )}
+    </>
+  )
+}
+
+type SavedWorldRowProps = {
+  session: SessionRecord
+  thumbnailSrc: string | undefined
+  seedAvailable: boolean
+  locale: string
+  onLoad: () => void
+  onShare: () => void
+  onDelete: () => void
+}
+
+const SavedWorldRow = ({
+  session,
+  thumbnailSrc,
+  seedAvailable,
+  locale,
+  onLoad,
+  onShare,
+  onDelete
+}: SavedWorldRowProps) => {
+  const { t } = useTranslation()
+  const subtitle = `${shortModelName(session.engine_model)} · ${formatDate(session.updated_at_ms, locale)}`
+
+  return (
+    <li className="flex items-stretch gap-[1.2cqh] border border-border-medium bg-white/5 p-[0.8cqh]">
+      <div className="h-[11cqh] w-[19.5cqh] shrink-0 self-center bg-black">
+        {thumbnailSrc && <img src={thumbnailSrc} alt="" className="size-full object-cover" draggable={false} />}
+      </div>
+      <div className="flex min-w-0 flex-1 flex-col justify-between gap-[0.4cqh]">
+        <div className="flex min-w-0 flex-col">
+          <span className="truncate font-serif text-[2.4cqh] text-text-primary">
+            {seedStem(session.seed_filename)}
+          </span>
+          <p
+            className={`
+              m-0 truncate
+              ${SETTINGS_MUTED_TEXT_WITHOUT_FONT_SIZE}
+            `}
+          >
+            {seedAvailable ? subtitle : t('app.dialogs.savedWorlds.seedMissing')}
+          </p>
+        </div>
+        <div className="flex shrink-0 justify-end gap-[0.8cqh]">
+          <Button
+            variant="danger"
+            autoShrinkLabel
+            label="app.buttons.delete"
+            className="px-[1.2cqh] py-[0.2cqh] text-[1.9cqh]"
+            onClick={onDelete}
+          />
+          <Button
+            variant="secondary"
+            autoShrinkLabel
+            label="app.dialogs.savedWorlds.share"
+            className="px-[1.2cqh] py-[0.2cqh] text-[1.9cqh]"
+            disabled={!seedAvailable}
+            onClick={onShare}
+          />
+          <Button
+            variant="secondary"
+            autoShrinkLabel
+            label="app.dialogs.savedWorlds.load"
+            className="px-[1.2cqh] py-[0.2cqh] text-[1.9cqh]"
+            disabled={!seedAvailable}
+            onClick={onLoad}
+          />
+        </div>
+      </div>
+    </li>
+  )
+}
+
+export default SavedWorldsModal
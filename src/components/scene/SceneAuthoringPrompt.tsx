@@ -1,6 +1,10 @@
-import { useState } from 'react'
+import { useEffect, useState } from 'react'
 import { useTranslation } from 'react-i18next'
 import { SETTINGS_CONTROL_BASE, SETTINGS_CONTROL_TEXT } from '../../styles'
+import { invoke } from '../../bridge'
+
+const MAX_SUGGESTIONS = 5
+const SUGGESTION_DEBOUNCE_MS = 120
 
 interface SceneAuthoringPromptProps {
   isGenerating: boolean
@@ -17,6 +21,37 @@ const SceneAuthoringPrompt = ({
 }: SceneAuthoringPromptProps) => {
   const { t } = useTranslation()
   const [promptText, setPromptText] = useState('')
+  const [isFocused, setIsFocused] = useState(false)
+  const [suggestions, setSuggestions] = useState<string[]>([])
+  const [highlighted, setHighlighted] = useState(-1)
+
+  // Past prompts matching what's typed so far; an empty box lists the most
+  // recent ones. Debounced so typing doesn't read the log on every key.
+  useEffect(() => {
+    if (!isFocused || isGenerating) {
+      setSuggestions([])
+      return
+    }
+    let cancelled = false
+    const timer = setTimeout(() => {
+      invoke('search-prompts', promptText, MAX_SUGGESTIONS)
+        .then((entries) => {
+          if (cancelled) return
+          setSuggestions(entries.map((e) => e.prompt).filter((p) => p !== promptText.trim()))
+          setHighlighted(-1)
+        })
+        .catch(() => null)
+    }, SUGGESTION_DEBOUNCE_MS)
+    return () => {
+      cancelled = true
+      clearTimeout(timer)
+    }
+  }, [promptText, isFocused, isGenerating])
+
+  const acceptSuggestion = (prompt: string) => {
+    setPromptText(prompt)
+    setSuggestions([])
+  }
 
   return (
     <>
@@ -38,6 +73,8 @@ const SceneAuthoringPrompt = ({
           rows={3}
           value={promptText}
           onChange={(e) => setPromptText(e.target.value)}
+          onFocus={() => setIsFocused(true)}
+          onBlur={() => setIsFocused(false)}
           disabled={isGenerating}
           placeholder={t('app.pause.generateScene.placeholder')}
           className={`
@@ -49,16 +86,66 @@ const SceneAuthoringPrompt = ({
           `}
           onKeyDown={(e) => {
             e.stopPropagation()
+            if (suggestions.length > 0) {
+              if (e.key === 'ArrowDown' || e.key === 'ArrowUp') {
+                e.preventDefault()
+                // Cycles through the suggestions and back to "none" (-1).
+                const slots = suggestions.length + 1
+                const step = e.key === 'ArrowDown' ? 1 : -1
+                setHighlighted((i) => ((i + 1 + step + slots) % slots) - 1)
+                return
+              }
+              if ((e.key === 'Tab' || e.key === 'Enter') && highlighted >= 0) {
+                e.preventDefault()
+                acceptSuggestion(suggestions[highlighted])
+                return
+              }
+              if (e.key === 'Escape') {
+                setSuggestions([])
+                return
+              }
+            }
             if (e.key === 'Enter' && !e.shiftKey) {
               e.preventDefault()
               const trimmed = promptText.trim()
               if (trimmed && !isGenerating) {
+                void invoke('add-prompt', trimmed)
                 onGenerate(trimmed)
               }
             }
           }}
           onKeyUp={(e) => e.stopPropagation()}
         />
+        {suggestions.length > 0 && (
+          <ul
+            role="listbox"
+            className={`
+              absolute right-0 bottom-full left-0 z-10 m-0 mb-[0.6cqh] list-none p-0 backdrop-blur-xl
+              ${SETTINGS_CONTROL_BASE}
+            `}
+          >
+            {suggestions.map((prompt, i) => (
+              <li
+                key={prompt}
+                role="option"
+                aria-selected={i === highlighted}
+                className={`
+                  cursor-pointer truncate p-[0.55cqh_1.42cqh] font-serif text-[2.2cqh] text-text-primary
+                  hover:bg-[rgba(245,251,255,0.08)]
+                  ${i === highlighted ? 'bg-[rgba(245,251,255,0.08)]' : ''}
+                `}
+                // mousedown, not click: the textarea would blur first and
+                // unmount the list before the click lands.
+                onMouseDown={(e) => {
+                  e.preventDefault()
+                  acceptSuggestion(prompt)
+                }}
+              >
+                {prompt}
+              </li>
+            ))}
+          </ul>
+        )}
         {isGenerating && (
           <div
             className="
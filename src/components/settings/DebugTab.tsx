@@ -1,8 +1,12 @@
-import { forwardRef, useCallback, useImperativeHandle, useState } from 'react'
+import { forwardRef, useCallback, useEffect, useImperativeHandle, useState } from 'react'
 import { useTranslation } from 'react-i18next'
+import { invoke } from '../../bridge'
 import { SETTINGS_MUTED_TEXT } from '../../styles'
 import { buildDiagnosticsPayload } from '../../lib/diagnosticsPayload'
+import { formatLogRecordPlainText } from '../../lib/logRecordText'
+import { formatDate } from '../../utils/formatDate'
 import { ENGINE_MODES, type Settings } from '../../types/settings'
+import type { CrashReportEntry } from '../../types/ipc'
 import { useConnection } from '../../context/streaming/connection'
 import { useWebsocket } from '../../context/streaming/websocket'
 import SettingsSection from '../ui/SettingsSection'
@@ -10,6 +14,11 @@ import SettingsRow from '../ui/SettingsRow'
 import SettingsCheckbox from '../ui/SettingsCheckbox'
 import Button from '../ui/Button'
 
+/** How much of the persistent app log "Copy recent" puts on the clipboard. */
+const APP_LOG_COPY_LINES = 500
+/** Newest crash reports listed; older ones stay reachable via "Show". */
+const CRASH_REPORTS_SHOWN = 5
+
 export type DebugTabHandle = {
   collectDraft: () => Partial<Settings>
 }
@@ -20,7 +29,7 @@ type DebugTabProps = {
 }
 
 const DebugTab = forwardRef<DebugTabHandle, DebugTabProps>(({ settings, active }, ref) => {
-  const { t } = useTranslation()
+  const { t, i18n } = useTranslation()
   const { server } = useConnection()
   const websocket = useWebsocket()
   const isServerMode = settings.engine_mode === ENGINE_MODES.SERVER
@@ -29,6 +38,18 @@ const DebugTab = forwardRef<DebugTabHandle, DebugTabProps>(({ settings, active }
   const [menuFrameTimeline, setMenuFrameTimeline] = useState(settings.debug_overlays.frame_timeline)
   const [menuActionLogging, setMenuActionLogging] = useState(settings.debug_overlays.action_logging)
   const [diagnosticsStatus, setDiagnosticsStatus] = useState<string | null>(null)
+  const [appLogPath, setAppLogPath] = useState('')
+  const [appLogStatus, setAppLogStatus] = useState<string | null>(null)
+  const [crashReports, setCrashReports] = useState<CrashReportEntry[]>([])
+
+  useEffect(() => {
+    invoke('get-app-log-path')
+      .then(setAppLogPath)
+      .catch(() => null)
+    invoke('list-crash-reports')
+      .then(setCrashReports)
+      .catch(() => null)
+  }, [])
 
   useImperativeHandle(
     ref,
@@ -77,6 +98,19 @@ const DebugTab = forwardRef<DebugTabHandle, DebugTabProps>(({ settings, active }
     t
   ])
 
+  // The persistent log spans previous runs, unlike the in-memory tail that
+  // goes into the diagnostics payload.
+  const handleCopyAppLog = useCallback(async () => {
+    setAppLogStatus(null)
+    try {
+      const records = await invoke('read-recent-logs', APP_LOG_COPY_LINES)
+      await navigator.clipboard.writeText(records.map(formatLogRecordPlainText).join('\n'))
+      setAppLogStatus(t('app.settings.debugMetrics.copiedToClipboard'))
+    } catch {
+      setAppLogStatus(t('app.settings.debugMetrics.copyFailed'))
+    }
+  }, [t])
+
   return (
     <div className={active ? 'flex flex-col gap-[2.3cqh]' : 'hidden'}>
       <SettingsSection title="app.settings.debugMetrics.title" description="app.settings.debugMetrics.description">
@@ -106,6 +140,69 @@ const DebugTab = forwardRef<DebugTabHandle, DebugTabProps>(({ settings, active }
               )}
             </div>
           </SettingsRow>
+          <SettingsRow
+            label={t('app.settings.debugMetrics.appLog')}
+            hint={t('app.settings.debugMetrics.appLogDescription', { count: APP_LOG_COPY_LINES, path: appLogPath })}
+            align="start"
+          >
+            <div className="flex items-center gap-[1.2cqh]">
+              <Button
+                variant="secondary"
+                autoShrinkLabel
+                label="app.buttons.copy"
+                className="px-[1.4cqh] py-[0.2cqh] text-[2cqh]"
+                onClick={() => void handleCopyAppLog()}
+              />
+              {appLogStatus && (
+                <span
+                  className={`
+                    font-serif text-[2cqh]
+                    ${SETTINGS_MUTED_TEXT}
+                  `}
+                >
+                  {appLogStatus}
+                </span>
+              )}
+            </div>
+          </SettingsRow>
+          <SettingsRow
+            label={t('app.settings.debugMetrics.crashReports')}
+            hint={
+              crashReports.length > 0
+                ? t('app.settings.debugMetrics.crashReportsDescription', { count: crashReports.length })
+                : t('app.settings.debugMetrics.crashReportsNone')
+            }
+            align="start"
+          >
+            {crashReports.length > 0 && (
+              <ul className="m-0 flex list-none flex-col gap-[0.6cqh] p-0">
+                {crashReports.slice(0, CRASH_REPORTS_SHOWN).map((report) => (
+                  <li key={report.path} className="flex items-center justify-end gap-[1.2cqh]">
+                    <span
+                      className={`
+                        font-serif text-[2cqh]
+                        ${SETTINGS_MUTED_TEXT}
+                      `}
+                    >
+                      {formatDate(report.created_at_ms, i18n.language)} ·{' '}
+                      {t(
+                        report.kind === 'minidump'
+                          ? 'app.settings.debugMetrics.crashKindMinidump'
+                          : 'app.settings.debugMetrics.crashKindReport'
+                      )}
+                    </span>
+                    <Button
+                      variant="secondary"
+                      autoShrinkLabel
+                      label="app.settings.debugMetrics.showCrashReport"
+                      className="px-[1.4cqh] py-[0.2cqh] text-[2cqh]"
+                      onClick={() => void invoke('reveal-crash-report', report.path)}
+                    />
+                  </li>
+                ))}
+              </ul>
+            )}
+          </SettingsRow>
           <SettingsCheckbox
             label="app.settings.debugMetrics.performanceStats"
             description="app.settings.debugMetrics.performanceStatsDescription"
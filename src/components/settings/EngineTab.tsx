@@ -1,6 +1,7 @@
 import { forwardRef, useCallback, useEffect, useImperativeHandle, useMemo, useRef, useState } from 'react'
 import { useTranslation } from 'react-i18next'
 import { invoke } from '../../bridge'
+import type { HfTokenCheckResult, LaunchPreview, OfflineInstallResult } from '../../types/ipc'
 import { SETTINGS_MUTED_TEXT } from '../../styles'
 import { ENGINE_MODES, localhostUrl, type EngineBackend, type QuantOption, type Settings } from '../../types/settings'
 import type { TranslationKey } from '../../i18n'
@@ -14,6 +15,7 @@ import SettingsSelect from '../ui/SettingsSelect'
 import SettingsTextInput from '../ui/SettingsTextInput'
 import SettingsCheckbox from '../ui/SettingsCheckbox'
 import SettingsRow from '../ui/SettingsRow'
+import SettingsButton from '../ui/SettingsButton'
 import ConfirmModal from '../ui/ConfirmModal'
 import EngineSection from '../engine/EngineSection'
 import EngineInstallModal from '../engine/EngineInstallModal'
@@ -41,6 +43,23 @@ const formatBytes = (bytes: number): string => {
   return `${(bytes / (1024 * 1024 * 1024)).toFixed(1)} GB`
 }
 
+/** `KEY=VALUE` pairs separated by whitespace, as typed into the launch
+ *  options field. Entries without a key are dropped. */
+const parseEnvPairs = (text: string): Record<string, string> =>
+  Object.fromEntries(
+    text
+      .split(/\s+/)
+      .filter((pair) => pair.indexOf('=') > 0)
+      .map((pair) => [pair.slice(0, pair.indexOf('=')), pair.slice(pair.indexOf('=') + 1)])
+  )
+
+const formatEnvPairs = (env: Record<string, string>): string =>
+  Object.entries(env)
+    .map(([key, value]) => `${key}=${value}`)
+    .join(' ')
+
+const quoteArg = (arg: string): string => (/[\s"']/.test(arg) ? JSON.stringify(arg) : arg)
+
 /** Tooltip explaining why a standalone-mode-gated control is disabled.
  *  Mirrors the lifecycle's terminal-vs-transient states so the user
  *  knows whether to wait, fix, or install. Returns undefined for
@@ -87,6 +106,7 @@ const EngineTab = forwardRef<EngineTabHandle, EngineTabProps>((props, ref) => {
   // dependent effect threads it through, and a fresh `?? []` literal
   // each render would retrigger the loader unnecessarily.
   const savedCustomModels = useMemo(() => settings.custom_models ?? [], [settings.custom_models])
+  const savedServerProfiles = useMemo(() => settings.server_profiles ?? [], [settings.server_profiles])
 
   const [menuServerUrl, setMenuServerUrl] = useState(configServerUrl)
   const [menuWorldModel, setMenuWorldModel] = useState(configWorldModel)
@@ -150,6 +170,32 @@ const EngineTab = forwardRef<EngineTabHandle, EngineTabProps>((props, ref) => {
     error: string | null
   }>({ state: 'idle', error: null })
 
+  // HuggingFace token for gated checkpoints. The token itself never comes
+  // back from main; `hfTokenCheck` is the whoami result for the stored
+  // token (or for the one just submitted, when it was rejected).
+  const [hfTokenDraft, setHfTokenDraft] = useState('')
+  const [hfTokenCheck, setHfTokenCheck] = useState<HfTokenCheckResult | null>(null)
+  const [hfTokenChecking, setHfTokenChecking] = useState(false)
+  // A running standalone server keeps the token it was spawned with, so a
+  // changed token only applies after a restart.
+  const [showHfRestartModal, setShowHfRestartModal] = useState(false)
+
+  // Launch options for the standalone server. Drafts commit on blur; the
+  // preview is re-fetched from main whenever the saved options change.
+  const savedLaunch = settings.engine_launch
+  const [gpuDeviceDraft, setGpuDeviceDraft] = useState(savedLaunch.gpu_device)
+  const [extraArgsDraft, setExtraArgsDraft] = useState(savedLaunch.extra_args.join(' '))
+  const [extraEnvDraft, setExtraEnvDraft] = useState(formatEnvPairs(savedLaunch.extra_env))
+  const [launchPreview, setLaunchPreview] = useState<LaunchPreview | null>(null)
+
+  // Air-gapped setup from local files. `offlineInstall` holds the outcome
+  // of the last step, shown under the row that ran it.
+  const [offlineInstallBusy, setOfflineInstallBusy] = useState(false)
+  const [offlineInstall, setOfflineInstall] = useState<{
+    step: 'archive' | 'cache'
+    result: OfflineInstallResult
+  } | null>(null)
+
   const [serverUrlStatus, setServerUrlStatus] = useState<ServerUrlStatus>('idle')
   const [lastValidatedServerUrl, setLastValidatedServerUrl] = useState('')
 
@@ -498,6 +544,87 @@ const EngineTab = forwardRef<EngineTabHandle, EngineTabProps>((props, ref) => {
     }
   }, [menuServerUrl, lastValidatedServerUrl, serverUrlStatus, setServerCapabilities])
 
+  // Picking a profile swaps the draft URL and resets the status to `idle`
+  // so the auto-validate effect above re-probes the new endpoint.
+  const handleSelectServerProfile = useCallback((url: string) => {
+    setMenuServerUrl(url)
+    setServerUrlStatus('idle')
+  }, [])
+
+  /** Remember the currently validated URL as a profile, named after its
+   *  host. Persisted immediately (like `custom_models`) so it survives
+   *  backing out of the menu without saving. */
+  const handleSaveServerProfile = useCallback(() => {
+    if (!lastValidatedServerUrl || savedServerProfiles.some((p) => p.url === lastValidatedServerUrl)) return
+    const profile = { name: new URL(lastValidatedServerUrl).host, url: lastValidatedServerUrl }
+    void saveSettings({ ...settings, server_profiles: [...savedServerProfiles, profile] })
+  }, [lastValidatedServerUrl, savedServerProfiles, settings, saveSettings])
+
+  const handleRemoveServerProfile = useCallback(
+    (url: string) => {
+      void saveSettings({ ...settings, server_profiles: savedServerProfiles.filter((p) => p.url !== url) })
+    },
+    [savedServerProfiles, settings, saveSettings]
+  )
+
+  useEffect(() => {
+    if (menuEngineMode !== 'standalone') return
+    invoke('check-stored-hf-token')
+      .then(setHfTokenCheck)
+      .catch(() => null)
+  }, [menuEngineMode])
+
+  const handleHfTokenBlur = useCallback(async () => {
+    if (!hfTokenDraft.trim()) return
+    setHfTokenChecking(true)
+    try {
+      const result = await invoke('set-hf-token', hfTokenDraft)
+      setHfTokenCheck(result)
+      setHfTokenDraft('')
+      if (result.status !== 'rejected' && lifecycle.isRunning) setShowHfRestartModal(true)
+    } catch {
+      setHfTokenCheck({ status: 'rejected', username: null, message: t('app.settings.hfToken.saveFailed') })
+    } finally {
+      setHfTokenChecking(false)
+    }
+  }, [hfTokenDraft, lifecycle.isRunning, t])
+
+  const handleClearHfToken = useCallback(async () => {
+    await invoke('clear-hf-token')
+    setHfTokenCheck(null)
+    if (lifecycle.isRunning) setShowHfRestartModal(true)
+  }, [lifecycle.isRunning])
+
+  const handleConfirmHfRestart = useCallback(() => {
+    setShowHfRestartModal(false)
+    void lifecycle.restartServer().catch(() => null)
+  }, [lifecycle])
+
+  const launchSignature = JSON.stringify(savedLaunch)
+  useEffect(() => {
+    if (menuEngineMode !== 'standalone') return
+    invoke('get-launch-preview')
+      .then(setLaunchPreview)
+      .catch(() => null)
+  }, [menuEngineMode, launchSignature])
+
+  const handleSaveLaunchOptions = useCallback(() => {
+    const next = {
+      gpu_device: gpuDeviceDraft.trim(),
+      extra_args: extraArgsDraft.split(/\s+/).filter(Boolean),
+      extra_env: parseEnvPairs(extraEnvDraft)
+    }
+    if (JSON.stringify(next) === launchSignature) return
+    void saveSettings({ ...settings, engine_launch: next })
+  }, [gpuDeviceDraft, extraArgsDraft, extraEnvDraft, launchSignature, settings, saveSettings])
+
+  const launchPreviewText = launchPreview
+    ? [
+        ...Object.entries(launchPreview.env).map(([key, value]) => `${key}=${quoteArg(value)}`),
+        [launchPreview.command, ...launchPreview.args].map(quoteArg).join(' ')
+      ].join('\n')
+    : ''
+
   const handleConfirmDeleteCache = useCallback(async () => {
     if (!showDeleteCacheModal) return
     const modelId = showDeleteCacheModal
@@ -554,6 +681,24 @@ const EngineTab = forwardRef<EngineTabHandle, EngineTabProps>((props, ref) => {
   // when there's nothing to nuke.
   const handleInstallEngine = () => runReinstall('fix')
 
+  const handleOfflineInstall = useCallback(
+    async (step: 'archive' | 'cache') => {
+      setOfflineInstallBusy(true)
+      try {
+        const result = await invoke(step === 'archive' ? 'install-uv-from-archive' : 'import-uv-cache')
+        if (!result) return
+        setOfflineInstall({ step, result })
+        if (result.ok) void checkEngine()
+      } finally {
+        setOfflineInstallBusy(false)
+      }
+    },
+    [checkEngine]
+  )
+
+  const offlineInstallHint = (step: 'archive' | 'cache', hint: TranslationKey) =>
+    offlineInstall?.step === step ? offlineInstall.result.message : t(hint)
+
   return (
     <div className={active ? 'flex flex-col gap-[2.3cqh]' : 'hidden'}>
       <SettingsSection title="app.settings.engineMode.title" description="app.settings.engineMode.description">
@@ -615,12 +760,37 @@ const EngineTab = forwardRef<EngineTabHandle, EngineTabProps>((props, ref) => {
             </span>
           }
         >
-          <SettingsTextInput
-            value={menuServerUrl}
-            onChange={setMenuServerUrl}
-            onBlur={() => void handleServerUrlBlur()}
-            placeholder="app.settings.serverUrl.placeholder"
-          />
+          <div className="flex flex-col gap-[1cqh]">
+            <SettingsTextInput
+              value={menuServerUrl}
+              onChange={setMenuServerUrl}
+              onBlur={() => void handleServerUrlBlur()}
+              placeholder="app.settings.serverUrl.placeholder"
+            />
+            {savedServerProfiles.length > 0 && (
+              <SettingsRow label={t('app.settings.serverUrl.profiles')}>
+                <SettingsSelect
+                  options={savedServerProfiles.map((p) => ({
+                    value: p.url,
+                    rawLabel: p.name,
+                    prefix: p.url,
+                    deletable: true
+                  }))}
+                  value={menuServerUrl}
+                  onChange={handleSelectServerProfile}
+                  onDelete={handleRemoveServerProfile}
+                  deleteLabel="app.settings.serverUrl.removeProfile"
+                />
+              </SettingsRow>
+            )}
+            {serverUrlStatus === 'valid' && !savedServerProfiles.some((p) => p.url === lastValidatedServerUrl) && (
+              <SettingsButton
+                variant="secondary"
+                label="app.settings.serverUrl.saveProfile"
+                onClick={handleSaveServerProfile}
+              />
+            )}
+          </div>
         </SettingsSection>
       )}
 
@@ -633,6 +803,134 @@ const EngineTab = forwardRef<EngineTabHandle, EngineTabProps>((props, ref) => {
         />
       )}
 
+      {menuEngineMode === 'standalone' && (
+        <SettingsSection
+          title="app.settings.offlineInstall.title"
+          description="app.settings.offlineInstall.description"
+        >
+          <div className="flex flex-col gap-[1cqh]">
+            <SettingsRow
+              label={t('app.settings.offlineInstall.uvArchive')}
+              hint={offlineInstallHint('archive', 'app.settings.offlineInstall.uvArchiveHint')}
+              hintError={offlineInstall?.step === 'archive' && !offlineInstall.result.ok}
+            >
+              <SettingsButton
+                variant="secondary"
+                label="app.settings.offlineInstall.choose"
+                disabled={offlineInstallBusy}
+                onClick={() => void handleOfflineInstall('archive')}
+              />
+            </SettingsRow>
+            <SettingsRow
+              label={t('app.settings.offlineInstall.uvCache')}
+              hint={offlineInstallHint('cache', 'app.settings.offlineInstall.uvCacheHint')}
+              hintError={offlineInstall?.step === 'cache' && !offlineInstall.result.ok}
+            >
+              <SettingsButton
+                variant="secondary"
+                label="app.settings.offlineInstall.choose"
+                disabled={offlineInstallBusy}
+                onClick={() => void handleOfflineInstall('cache')}
+              />
+            </SettingsRow>
+          </div>
+        </SettingsSection>
+      )}
+
+      {menuEngineMode === 'standalone' && (
+        <SettingsSection title="app.settings.hfToken.title" description="app.settings.hfToken.description">
+          <SettingsRow
+            label={t('app.settings.hfToken.token')}
+            hint={
+              hfTokenChecking
+                ? t('app.settings.hfToken.checking')
+                : hfTokenCheck?.status === 'valid'
+                  ? t('app.settings.hfToken.saved', { username: hfTokenCheck.username ?? '?' })
+                  : (hfTokenCheck?.message ?? undefined)
+            }
+            hintError={!hfTokenChecking && hfTokenCheck?.status === 'rejected'}
+          >
+            <div className="flex items-center gap-[0.6cqh]">
+              <div className="min-w-0 flex-1">
+                <SettingsTextInput
+                  value={hfTokenDraft}
+                  onChange={setHfTokenDraft}
+                  onBlur={() => void handleHfTokenBlur()}
+                  placeholder="app.settings.hfToken.placeholder"
+                  disabled={hfTokenChecking}
+                  secret
+                />
+              </div>
+              {hfTokenCheck !== null && (
+                <SettingsButton
+                  variant="secondary"
+                  label="app.settings.hfToken.clear"
+                  onClick={() => void handleClearHfToken()}
+                />
+              )}
+            </div>
+          </SettingsRow>
+        </SettingsSection>
+      )}
+
+      {menuEngineMode === 'standalone' && (
+        <SettingsSection title="app.settings.engineLaunch.title" description="app.settings.engineLaunch.description">
+          <div className="flex flex-col gap-[1cqh]">
+            <SettingsRow
+              label={t('app.settings.engineLaunch.gpuDevice')}
+              hint={t('app.settings.engineLaunch.gpuDeviceHint')}
+            >
+              <SettingsTextInput
+                value={gpuDeviceDraft}
+                onChange={setGpuDeviceDraft}
+                onBlur={handleSaveLaunchOptions}
+                rawPlaceholder="0"
+              />
+            </SettingsRow>
+            <SettingsRow
+              label={t('app.settings.engineLaunch.extraArgs')}
+              hint={t('app.settings.engineLaunch.extraArgsHint')}
+            >
+              <SettingsTextInput
+                value={extraArgsDraft}
+                onChange={setExtraArgsDraft}
+                onBlur={handleSaveLaunchOptions}
+                rawPlaceholder="--flag value"
+              />
+            </SettingsRow>
+            <SettingsRow
+              label={t('app.settings.engineLaunch.extraEnv')}
+              hint={t('app.settings.engineLaunch.extraEnvHint')}
+            >
+              <SettingsTextInput
+                value={extraEnvDraft}
+                onChange={setExtraEnvDraft}
+                onBlur={handleSaveLaunchOptions}
+                rawPlaceholder="KEY=VALUE"
+              />
+            </SettingsRow>
+            {launchPreview && (
+              <SettingsRow label={t('app.settings.engineLaunch.preview')} align="start">
+                <pre
+                  className="
+                    m-0 border border-border-subtle bg-white/5 p-[1.2cqh] font-mono text-[1.6cqh]
+                    break-all whitespace-pre-wrap text-text-modal-muted
+                  "
+                >
+                  {launchPreviewText}
+                </pre>
+              </SettingsRow>
+            )}
+            <SettingsCheckbox
+              label="app.settings.engineLaunch.autoRestart"
+              description="app.settings.engineLaunch.autoRestartDescription"
+              checked={settings.engine_auto_restart}
+              onChange={(v) => void saveSettings({ ...settings, engine_auto_restart: v })}
+            />
+          </div>
+        </SettingsSection>
+      )}
+
       <SettingsSection title="app.settings.simulation.title" description="app.settings.simulation.description">
         <div className="flex flex-col gap-[1cqh]">
           <SettingsRow
@@ -788,6 +1086,17 @@ const EngineTab = forwardRef<EngineTabHandle, EngineTabProps>((props, ref) => {
         />
       )}
 
+      {showHfRestartModal && (
+        <ConfirmModal
+          title="app.dialogs.restartForHfToken.title"
+          description="app.dialogs.restartForHfToken.description"
+          onCancel={() => setShowHfRestartModal(false)}
+          onConfirm={handleConfirmHfRestart}
+          confirmLabel="app.buttons.restartEngine"
+          cancelLabel="app.buttons.later"
+        />
+      )}
+
       {showDeleteCacheModal && (
         <ConfirmModal
           title="app.dialogs.deleteModelCache.title"
@@ -1,10 +1,11 @@
 import { useCallback, useEffect, useState } from 'react'
 import { useTranslation } from 'react-i18next'
 import { invoke } from '../../bridge'
-import { LOCALE_DISPLAY_NAMES, SUPPORTED_LOCALES } from '../../i18n'
+import { LOCALE_DISPLAY_NAMES, SUPPORTED_LOCALES, type TranslationKey } from '../../i18n'
 import { useSettings } from '../../hooks/settings/settingsContextValue'
 import { useVolumeControls } from '../../hooks/audio/useVolumeControls'
-import { type AppLocale } from '../../types/settings'
+import { type AppLocale, type Settings, type UpdateChannel } from '../../types/settings'
+import type { MonitorInfo, SettingsIssue } from '../../types/ipc'
 import { SETTINGS_CONTROL_VMETRICS } from '../../styles'
 import SettingsSection from '../ui/SettingsSection'
 import SettingsSelect from '../ui/SettingsSelect'
@@ -15,6 +16,50 @@ import SettingsTextInput from '../ui/SettingsTextInput'
 import Button from '../ui/Button'
 import RecordingsModal from './RecordingsModal'
 
+const IDLE_PAUSE_MINUTES = [5, 10, 15, 30]
+
+type NetworkSettings = Settings['network']
+
+type NetworkField = {
+  key: keyof NetworkSettings
+  label: TranslationKey
+  hint: TranslationKey
+  placeholder: string
+}
+
+const NETWORK_FIELDS: NetworkField[] = [
+  {
+    key: 'proxy',
+    label: 'app.settings.network.proxy',
+    hint: 'app.settings.network.proxyHint',
+    placeholder: 'http://proxy.example.com:8080'
+  },
+  {
+    key: 'uv_mirror',
+    label: 'app.settings.network.uvMirror',
+    hint: 'app.settings.network.uvMirrorHint',
+    placeholder: 'https://github.com/astral-sh/uv/releases/download'
+  },
+  {
+    key: 'pypi_mirror',
+    label: 'app.settings.network.pypiMirror',
+    hint: 'app.settings.network.pypiMirrorHint',
+    placeholder: 'https://pypi.org/simple'
+  },
+  {
+    key: 'python_mirror',
+    label: 'app.settings.network.pythonMirror',
+    hint: 'app.settings.network.pythonMirrorHint',
+    placeholder: 'https://github.com/astral-sh/python-build-standalone/releases/download'
+  },
+  {
+    key: 'hf_mirror',
+    label: 'app.settings.network.hfMirror',
+    hint: 'app.settings.network.hfMirrorHint',
+    placeholder: 'https://huggingface.co'
+  }
+]
+
 type GeneralTabProps = {
   active: boolean
   menuEngineMode: 'server' | 'standalone'
@@ -22,6 +67,9 @@ type GeneralTabProps = {
   setMenuSceneAuthoringEnabled: (enabled: boolean) => void
   menuOfflineMode: boolean
   setMenuOfflineMode: (enabled: boolean) => void
+  /** Called after `import-settings` replaced settings.json, so the parent
+   *  can reset its drafts instead of saving stale ones over the import. */
+  onSettingsImported: (settings: Settings) => void
 }
 
 const GeneralTab = ({
@@ -30,10 +78,11 @@ const GeneralTab = ({
   menuSceneAuthoringEnabled,
   setMenuSceneAuthoringEnabled,
   menuOfflineMode,
-  setMenuOfflineMode
+  setMenuOfflineMode,
+  onSettingsImported
 }: GeneralTabProps) => {
   const { t } = useTranslation()
-  const { settings, saveSettings } = useSettings()
+  const { settings, saveSettings, reloadSettings } = useSettings()
   const volume = useVolumeControls()
   const [menuLocale, setMenuLocale] = useState<AppLocale>(settings.locale)
 
@@ -42,6 +91,20 @@ const GeneralTab = ({
   const [draftDir, setDraftDir] = useState(configuredDir)
   const [defaultDir, setDefaultDir] = useState('')
   const [showRecordingsModal, setShowRecordingsModal] = useState(false)
+  const [importIssues, setImportIssues] = useState<SettingsIssue[] | null>(null)
+  const [promptHistoryCleared, setPromptHistoryCleared] = useState(false)
+  const [monitors, setMonitors] = useState<MonitorInfo[]>([])
+
+  // Network drafts commit on blur, after `validate-settings` has checked
+  // the URLs; problems are shown under the offending field.
+  const [networkDraft, setNetworkDraft] = useState<NetworkSettings>(settings.network)
+  const [networkIssues, setNetworkIssues] = useState<SettingsIssue[]>([])
+  // Keyed on the serialised value so saving some other setting doesn't
+  // reset a half-typed draft.
+  const networkSignature = JSON.stringify(settings.network)
+  useEffect(() => {
+    setNetworkDraft(JSON.parse(networkSignature) as NetworkSettings)
+  }, [networkSignature])
 
   // Keep the draft text input in sync with external setting changes (e.g. Browse dialog)
   useEffect(() => {
@@ -52,6 +115,9 @@ const GeneralTab = ({
     invoke('get-default-video-dir')
       .then(setDefaultDir)
       .catch(() => null)
+    invoke('list-monitors')
+      .then(setMonitors)
+      .catch(() => null)
   }, [])
 
   const handleLocaleChange = (locale: AppLocale) => {
@@ -59,6 +125,25 @@ const GeneralTab = ({
     void saveSettings({ ...settings, locale })
   }
 
+  const handleUpdateChannelChange = (update_channel: UpdateChannel) => {
+    void saveSettings({ ...settings, update_channel })
+  }
+
+  const handleIdlePauseChange = (value: string) => {
+    void saveSettings({ ...settings, display: { ...settings.display, idle_pause_minutes: Number(value) } })
+  }
+
+  const preferredMonitor = settings.display.preferred_monitor
+  // Picked from the pause menu mid-session, the change applies right away;
+  // otherwise it waits for the next session.
+  const handlePreferredMonitorChange = (value: string) => {
+    const preferred_monitor = value === 'current' ? null : Number(value)
+    void saveSettings({ ...settings, display: { ...settings.display, preferred_monitor } })
+    if (preferred_monitor !== null) {
+      invoke('move-session-to-monitor', preferred_monitor).catch(() => null)
+    }
+  }
+
   const saveRecordingPatch = useCallback(
     (patch: Partial<{ enabled: boolean; output_dir: string }>) => {
       void saveSettings({
@@ -84,6 +169,51 @@ const GeneralTab = ({
     setShowRecordingsModal(true)
   }, [])
 
+  const handleClearPromptHistory = useCallback(() => {
+    invoke('clear-prompt-history')
+      .then(() => setPromptHistoryCleared(true))
+      .catch(() => null)
+  }, [])
+
+  const handleExportSettings = useCallback(() => {
+    invoke('export-settings').catch(() => null)
+  }, [])
+
+  const handleImportSettings = useCallback(async () => {
+    try {
+      const result = await invoke('import-settings')
+      if (!result) return
+      if (!result.ok) {
+        setImportIssues(result.issues)
+        return
+      }
+      setImportIssues(null)
+      await reloadSettings()
+      onSettingsImported(result.settings)
+    } catch {
+      setImportIssues([])
+    }
+  }, [reloadSettings, onSettingsImported])
+
+  const handleSaveNetwork = useCallback(async () => {
+    if (JSON.stringify(networkDraft) === networkSignature) {
+      setNetworkIssues([])
+      return
+    }
+    const candidate = { ...settings, network: networkDraft }
+    const issues = (await invoke('validate-settings', candidate)).filter((issue) => issue.path.startsWith('network.'))
+    setNetworkIssues(issues)
+    if (issues.length === 0) await saveSettings(candidate)
+  }, [networkDraft, networkSignature, settings, saveSettings])
+
+  // An empty issue list means the import failed for a reason other than
+  // the file's contents (e.g. settings.json couldn't be written).
+  const importIssuesText =
+    importIssues &&
+    (importIssues.length > 0
+      ? importIssues.map((issue) => t(issue.message_id, issue.params)).join(' ')
+      : t('app.settings.settingsBackup.importFailed'))
+
   const isStandalone = menuEngineMode === 'standalone'
   const showRecording = isStandalone
   const showOfflineMode = isStandalone
@@ -104,6 +234,75 @@ const GeneralTab = ({
         />
       </SettingsSection>
 
+      <SettingsSection title="app.settings.updateChannel.title" description="app.settings.updateChannel.description">
+        <SettingsSelect
+          options={[
+            { value: 'stable', label: 'app.settings.updateChannel.stable' },
+            { value: 'beta', label: 'app.settings.updateChannel.beta' }
+          ]}
+          value={settings.update_channel}
+          onChange={(value) => handleUpdateChannelChange(value as UpdateChannel)}
+        />
+      </SettingsSection>
+
+      <SettingsSection title="app.settings.tray.title" description="app.settings.tray.description">
+        <SettingsCheckbox
+          label="app.settings.tray.enabled"
+          description="app.settings.tray.enabledDescription"
+          checked={settings.minimize_to_tray}
+          onChange={(v) => void saveSettings({ ...settings, minimize_to_tray: v })}
+        />
+      </SettingsSection>
+
+      <SettingsSection title="app.settings.notifications.title" description="app.settings.notifications.description">
+        <SettingsCheckbox
+          label="app.settings.notifications.enabled"
+          description="app.settings.notifications.enabledDescription"
+          checked={settings.notifications.enabled}
+          onChange={(enabled) => void saveSettings({ ...settings, notifications: { enabled } })}
+        />
+      </SettingsSection>
+
+      <SettingsSection title="app.settings.display.title" description="app.settings.display.description">
+        <SettingsCheckbox
+          label="app.settings.display.sessionFullscreen"
+          description="app.settings.display.sessionFullscreenDescription"
+          checked={settings.display.session_fullscreen}
+          onChange={(session_fullscreen) =>
+            void saveSettings({ ...settings, display: { ...settings.display, session_fullscreen } })
+          }
+        />
+        {monitors.length > 1 && (
+          <SettingsRow label={t('app.settings.display.monitor')}>
+            <SettingsSelect
+              options={[
+                { value: 'current', label: 'app.settings.display.currentMonitor' },
+                ...monitors.map((monitor) => ({
+                  value: String(monitor.id),
+                  rawLabel: `${monitor.label} (${monitor.width}×${monitor.height})`,
+                  prefix: monitor.primary ? t('app.settings.display.primary') : undefined
+                }))
+              ]}
+              value={preferredMonitor === null ? 'current' : String(preferredMonitor)}
+              onChange={handlePreferredMonitorChange}
+            />
+          </SettingsRow>
+        )}
+        <SettingsRow label={t('app.settings.display.idlePause')}>
+          <SettingsSelect
+            options={[
+              { value: '0', label: 'app.settings.display.idlePauseNever' },
+              ...IDLE_PAUSE_MINUTES.map((minutes) => ({
+                value: String(minutes),
+                rawLabel: t('app.settings.display.idlePauseMinutes', { minutes })
+              }))
+            ]}
+            value={String(settings.display.idle_pause_minutes)}
+            onChange={handleIdlePauseChange}
+          />
+        </SettingsRow>
+      </SettingsSection>
+
       <SettingsSection title="app.settings.volume.title" description="app.settings.volume.description">
         <div className="flex flex-col gap-[1.5cqh]">
           <Slider
@@ -147,6 +346,24 @@ const GeneralTab = ({
             checked={settings.scene_authoring_save_generated ?? true}
             onChange={(v) => void saveSettings({ ...settings, scene_authoring_save_generated: v })}
           />
+          <SettingsRow
+            label={t('app.settings.sceneAuthoring.promptHistory')}
+            hint={t('app.settings.sceneAuthoring.promptHistoryDescription')}
+            align="start"
+          >
+            <Button
+              variant="secondary"
+              autoShrinkLabel
+              label={
+                promptHistoryCleared
+                  ? 'app.settings.sceneAuthoring.promptHistoryCleared'
+                  : 'app.settings.sceneAuthoring.clearPromptHistory'
+              }
+              className="px-[1.4cqh] py-[0.2cqh] text-[2cqh]"
+              disabled={promptHistoryCleared}
+              onClick={handleClearPromptHistory}
+            />
+          </SettingsRow>
         </div>
       </SettingsSection>
 
@@ -205,6 +422,30 @@ const GeneralTab = ({
         </SettingsSection>
       )}
 
+      <SettingsSection title="app.settings.screenshots.title" description="app.settings.screenshots.description">
+        <div className="flex flex-col gap-[1cqh]">
+          <SettingsCheckbox
+            label="app.settings.screenshots.copyToClipboard"
+            description="app.settings.screenshots.copyToClipboardDescription"
+            checked={settings.screenshots.copy_to_clipboard}
+            onChange={(v) => void saveSettings({ ...settings, screenshots: { copy_to_clipboard: v } })}
+          />
+          <SettingsRow
+            label={t('app.settings.screenshots.folder')}
+            hint={t('app.settings.screenshots.folderDescription')}
+            align="start"
+          >
+            <Button
+              variant="secondary"
+              autoShrinkLabel
+              label="app.buttons.open"
+              className="px-[1.4cqh] py-[0.2cqh] text-[2cqh]"
+              onClick={() => void invoke('open-screenshots-folder')}
+            />
+          </SettingsRow>
+        </div>
+      </SettingsSection>
+
       {showOfflineMode && (
         <SettingsSection title="app.settings.offlineMode.title" description="app.settings.offlineMode.description">
           <SettingsCheckbox
@@ -216,6 +457,55 @@ const GeneralTab = ({
         </SettingsSection>
       )}
 
+      <SettingsSection title="app.settings.network.title" description="app.settings.network.description">
+        <div className="flex flex-col gap-[1cqh]">
+          {NETWORK_FIELDS.map((field) => {
+            const issue = networkIssues.find((i) => i.path === `network.${field.key}`)
+            return (
+              <SettingsRow
+                key={field.key}
+                label={t(field.label)}
+                hint={issue ? t(issue.message_id, issue.params) : t(field.hint)}
+                hintError={issue !== undefined}
+              >
+                <SettingsTextInput
+                  value={networkDraft[field.key]}
+                  onChange={(value) => setNetworkDraft((draft) => ({ ...draft, [field.key]: value }))}
+                  onBlur={() => void handleSaveNetwork()}
+                  rawPlaceholder={field.placeholder}
+                />
+              </SettingsRow>
+            )
+          })}
+        </div>
+      </SettingsSection>
+
+      <SettingsSection title="app.settings.settingsBackup.title" description="app.settings.settingsBackup.description">
+        <SettingsRow
+          label={t('app.settings.settingsBackup.file')}
+          hint={importIssuesText ?? undefined}
+          hintError={importIssues !== null}
+          align="start"
+        >
+          <div className="flex items-center gap-[0.6cqh]">
+            <Button
+              variant="secondary"
+              autoShrinkLabel
+              label="app.settings.settingsBackup.export"
+              className="px-[1.4cqh] py-[0.2cqh] text-[2cqh]"
+              onClick={handleExportSettings}
+            />
+            <Button
+              variant="secondary"
+              autoShrinkLabel
+              label="app.settings.settingsBackup.import"
+              className="px-[1.4cqh] py-[0.2cqh] text-[2cqh]"
+              onClick={() => void handleImportSettings()}
+            />
+          </div>
+        </SettingsRow>
+      </SettingsSection>
+
       {showRecordingsModal && (
         <RecordingsModal configuredDir={draftDir || defaultDir} onClose={() => setShowRecordingsModal(false)} />
       )}
@@ -3,6 +3,7 @@ import { Trans, useTranslation } from 'react-i18next'
 import { DEFAULT_KEYBINDINGS, type ControlBindKey, type Keybindings, type Settings } from '../../types/settings'
 import { GAME_ACTIONS, getKeybindConflict } from '../../hooks/input/useGameInput'
 import SettingsSection from '../ui/SettingsSection'
+import SettingsCheckbox from '../ui/SettingsCheckbox'
 import Slider from '../ui/Slider'
 import Button from '../ui/Button'
 import KeybindRow from './KeybindRow'
@@ -46,6 +47,7 @@ const KeyboardTab = forwardRef<KeyboardTabHandle, KeyboardTabProps>(
       sensitivityToMenu(settings.mouse_sensitivity)
     )
     const [menuKeybindings, setMenuKeybindings] = useState<Keybindings>(() => ({ ...settings.keybindings }))
+    const [menuToggleMovement, setMenuToggleMovement] = useState(settings.accessibility.toggle_movement)
 
     /** Keybind actions currently rendered in the UI. Scene Authoring-gated
      *  actions (scene edit) vanish from both the render and the conflict pool
@@ -72,10 +74,11 @@ const KeyboardTab = forwardRef<KeyboardTabHandle, KeyboardTabProps>(
       () => ({
         collectDraft: () => ({
           mouse_sensitivity: sensitivityFromMenu(menuMouseSensitivity),
-          keybindings: menuKeybindings
+          keybindings: menuKeybindings,
+          accessibility: { toggle_movement: menuToggleMovement }
         })
       }),
-      [menuMouseSensitivity, menuKeybindings]
+      [menuMouseSensitivity, menuKeybindings, menuToggleMovement]
     )
 
     return (
@@ -137,6 +140,15 @@ const KeyboardTab = forwardRef<KeyboardTabHandle, KeyboardTabProps>(
             </div>
           )}
         </SettingsSection>
+
+        <SettingsSection title="app.settings.accessibility.title" description="app.settings.accessibility.description">
+          <SettingsCheckbox
+            label="app.settings.accessibility.toggleMovement"
+            description="app.settings.accessibility.toggleMovementDescription"
+            checked={menuToggleMovement}
+            onChange={setMenuToggleMovement}
+          />
+        </SettingsSection>
       </div>
     )
   }
@@ -53,6 +53,9 @@ const MenuSettingsView = ({ onBack }: MenuSettingsViewProps) => {
   const [hasKeybindConflict, setHasKeybindConflict] = useState(false)
   const [showModeSwitchModal, setShowModeSwitchModal] = useState(false)
   const [showCredits, setShowCredits] = useState(false)
+  // Bumped after a settings import to remount the tabs, which seed their
+  // drafts from `settings` only on mount.
+  const [draftGeneration, setDraftGeneration] = useState(0)
 
   const engineRef = useRef<EngineTabHandle>(null)
   const keyboardRef = useRef<KeyboardTabHandle>(null)
@@ -81,6 +84,19 @@ const MenuSettingsView = ({ onBack }: MenuSettingsViewProps) => {
     }
   }, [settings, volume, menuSceneAuthoringEnabled, menuOfflineMode])
 
+  const handleSettingsImported = useCallback(
+    (imported: Settings) => {
+      setMenuSceneAuthoringEnabled(imported.scene_authoring_enabled)
+      setMenuOfflineMode(imported.offline_mode)
+      setMenuEngineMode(imported.engine_mode === ENGINE_MODES.SERVER ? 'server' : 'standalone')
+      volume.setMaster(Math.round(imported.audio.master_volume * 100))
+      volume.setSfx(Math.round(imported.audio.sfx_volume * 100))
+      volume.setMusic(Math.round(imported.audio.music_volume * 100))
+      setDraftGeneration((g) => g + 1)
+    },
+    [volume]
+  )
+
   const applyDraftSettings = useCallback(async () => {
     await saveSettings(buildPendingSettings())
   }, [saveSettings, buildPendingSettings])
@@ -134,7 +150,7 @@ const MenuSettingsView = ({ onBack }: MenuSettingsViewProps) => {
               onChange={(v) => setActiveTab(v as SettingsTab)}
             />
           </div>
-          <div className="styled-scrollbar min-h-0 flex-1 overflow-y-auto px-[2cqh] pb-[1.0cqh]">
+          <div key={draftGeneration} className="styled-scrollbar min-h-0 flex-1 overflow-y-auto px-[2cqh] pb-[1.0cqh]">
             <GeneralTab
               active={activeTab === 'general'}
               menuEngineMode={menuEngineMode}
@@ -142,6 +158,7 @@ const MenuSettingsView = ({ onBack }: MenuSettingsViewProps) => {
               setMenuSceneAuthoringEnabled={setMenuSceneAuthoringEnabled}
               menuOfflineMode={menuOfflineMode}
               setMenuOfflineMode={setMenuOfflineMode}
+              onSettingsImported={handleSettingsImported}
             />
             <EngineTab
               ref={engineRef}
@@ -3,6 +3,7 @@ import { useTranslation } from 'react-i18next'
 import { invoke } from '../../bridge'
 import type { RecordingEntry } from '../../types/ipc'
 import { SETTINGS_MUTED_TEXT, SETTINGS_MUTED_TEXT_WITHOUT_FONT_SIZE } from '../../styles'
+import { formatDate } from '../../utils/formatDate'
 import Modal from '../ui/Modal'
 import ConfirmModal from '../ui/ConfirmModal'
 import Button from '../ui/Button'
@@ -19,12 +20,6 @@ const formatBytes = (bytes: number): string => {
   return `${(bytes / (1024 * 1024 * 1024)).toFixed(2)} GB`
 }
 
-const formatDate = (mtimeMs: number, locale: string): string => {
-  const d = new Date(mtimeMs)
-  const resolved = locale === 'goose' ? undefined : locale
-  return new Intl.DateTimeFormat(resolved, { dateStyle: 'medium', timeStyle: 'short' }).format(d)
-}
-
 const RecordingsModal = ({ configuredDir, onClose }: RecordingsModalProps) => {
   const { t, i18n } = useTranslation()
   const [entries, setEntries] = useState<RecordingEntry[]>([])
@@ -0,0 +1,51 @@
+import { useEffect, useRef, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import { listen } from '../../bridge'
+import type { ScreenshotResult } from '../../types/ipc'
+
+const TOAST_MS = 2000
+
+/** Brief "Screenshot saved" confirmation after the screenshot keybind.
+ *  Listens for main's `screenshot-saved` broadcast rather than the invoke
+ *  result, so it shows however the capture was triggered. */
+const ScreenshotToast = () => {
+  const { t } = useTranslation()
+  const [shot, setShot] = useState<ScreenshotResult | null>(null)
+  const toastKeyRef = useRef(0)
+
+  useEffect(
+    () =>
+      listen('screenshot-saved', (result) => {
+        toastKeyRef.current += 1
+        setShot(result)
+      }),
+    []
+  )
+
+  useEffect(() => {
+    if (!shot) return
+    const timer = window.setTimeout(() => setShot(null), TOAST_MS)
+    return () => window.clearTimeout(timer)
+  }, [shot])
+
+  if (!shot) return null
+
+  return (
+    <div
+      key={toastKeyRef.current}
+      className="pointer-events-none absolute top-[3.2cqh] left-1/2 z-180 max-w-[80cqw] -translate-x-1/2"
+    >
+      <div
+        className="
+          border border-white/20 bg-black/70 px-[2.1cqh] py-[0.9cqh] text-center font-serif text-[2cqh]
+          tracking-[0.01em] text-white/90 shadow-lg backdrop-blur-sm
+        "
+        style={{ animation: `streamingWarningToast ${TOAST_MS}ms ease forwards` }}
+      >
+        {t(shot.copied_to_clipboard ? 'app.screenshots.savedAndCopied' : 'app.screenshots.saved')}
+      </div>
+    </div>
+  )
+}
+
+export default ScreenshotToast
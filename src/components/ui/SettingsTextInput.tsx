@@ -10,6 +10,8 @@ type SettingsTextInputProps = {
   /** Escape hatch for placeholders that aren't translation keys (dynamic paths, etc.). */
   rawPlaceholder?: string
   disabled?: boolean
+  /** Mask the value (tokens and other credentials). */
+  secret?: boolean
 }
 
 const SettingsTextInput = ({
@@ -18,13 +20,14 @@ const SettingsTextInput = ({
   onBlur,
   placeholder,
   rawPlaceholder,
-  disabled
+  disabled,
+  secret
 }: SettingsTextInputProps) => {
   const { t } = useTranslation()
 
   return (
     <input
-      type="text"
+      type={secret ? 'password' : 'text'}
       className={`
         w-full cursor-text rounded-none
         ${SETTINGS_CONTROL_BASE}
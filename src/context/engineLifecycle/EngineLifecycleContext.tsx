@@ -1,9 +1,11 @@
 import { useCallback, useEffect, useMemo, useRef, useState, type ReactNode } from 'react'
-import { invoke } from '../../bridge'
+import { invoke, listen } from '../../bridge'
 import { STANDALONE_PORT } from '../../types/settings'
 import { useSettings } from '../../hooks/settings/settingsContextValue'
 import useEngineApi from '../../hooks/engine/useEngineApi'
 import { createLogger } from '../../utils/logger'
+import i18n from '../../i18n'
+import type { TrayEngineState } from '../../types/ipc'
 import {
   EngineLifecycleContext,
   type EngineLifecycleContextValue,
@@ -12,8 +14,26 @@ import {
 
 const log = createLogger('EngineLifecycle')
 
+/** Crash auto-restart backoff: the first restart waits
+ *  `AUTO_RESTART_BASE_DELAY_MS`, doubling per consecutive crash up to
+ *  `AUTO_RESTART_MAX_DELAY_MS`. After `AUTO_RESTART_MAX_ATTEMPTS` crashes
+ *  without `AUTO_RESTART_STABLE_MS` of uptime in between, we stop retrying
+ *  and surface the failure instead of looping on a broken install. */
+const AUTO_RESTART_BASE_DELAY_MS = 2000
+const AUTO_RESTART_MAX_DELAY_MS = 60_000
+const AUTO_RESTART_MAX_ATTEMPTS = 5
+const AUTO_RESTART_STABLE_MS = 120_000
+
+const TRAY_ENGINE_STATES: Record<LifecycleState['kind'], TrayEngineState> = {
+  preparing: 'starting',
+  ready: 'ready',
+  not_installed: 'stopped',
+  failed: 'error'
+}
+
 export const EngineLifecycleProvider = ({ children }: { children: ReactNode }) => {
-  const { isStandaloneMode: savedStandalone } = useSettings()
+  const { settings, isStandaloneMode: savedStandalone } = useSettings()
+  const autoRestart = settings.engine_auto_restart
   const engine = useEngineApi()
   const [state, setState] = useState<LifecycleState>(() => ({ kind: 'preparing' }))
 
@@ -133,26 +153,83 @@ export const EngineLifecycleProvider = ({ children }: { children: ReactNode }) =
     await invoke('abort-engine-install')
   }, [])
 
+  // Consecutive crash restarts. Reset once the server has stayed up for
+  // `AUTO_RESTART_STABLE_MS`, so only crash loops hit the cap.
+  const crashRestartsRef = useRef(0)
+  useEffect(() => {
+    if (state.kind !== 'ready' || !engine.isServerRunning) return
+    const timer = setTimeout(() => {
+      crashRestartsRef.current = 0
+    }, AUTO_RESTART_STABLE_MS)
+    return () => clearTimeout(timer)
+  }, [state.kind, engine.isServerRunning])
+
   // Reconcile `state.kind === 'ready'` with whether the server is
   // *actually* running. The state machine can drift from reality if
-  // the server crashes on its own — there are no longer any callers
-  // that intentionally kill it without going through `restartServer`
-  // (which keeps the state honest), but a Python crash, OOM kill,
-  // user-side `pkill`, etc. would all leave `state.kind === 'ready'`
-  // pointing at a dead process. Auto-recover by firing a fresh
-  // `restartServer`. Gated on `engine.status !== null` so the very
-  // first probe (which races mount) doesn't trip the recovery before
-  // status is known. The `runExclusive` lock means concurrent
-  // restarts coalesce — once `state.kind` moves to `'preparing'`,
-  // this effect re-runs and bails on the kind check.
+  // the server crashes on its own — intentional stops all go through
+  // `restartServer` / `reinstallEngine` (which keep the state honest),
+  // but a Python crash, OOM kill, user-side `pkill`, etc. would all
+  // leave `state.kind === 'ready'` pointing at a dead process.
+  // Auto-recover by firing a fresh `restartServer` after a backoff, or
+  // move to `failed` when `engine_auto_restart` is off or the crash
+  // cap is hit. Gated on a known status so the very first probe (which
+  // races mount) doesn't trip the recovery before status is known. The
+  // `runExclusive` lock means concurrent restarts coalesce — once
+  // `state.kind` moves to `'preparing'`, this effect re-runs and bails
+  // on the kind check.
+  const statusKnown = engine.status !== null
   useEffect(() => {
     if (!isStandaloneMode) return
     if (state.kind !== 'ready') return
-    if (engine.status === null) return
+    if (!statusKnown) return
     if (engine.isServerRunning) return
-    log.warn('Standalone server not running while lifecycle state is ready - auto-recovering')
-    void restartServer().catch((err) => log.error('Auto-recover restart failed:', errorMessage(err)))
-  }, [isStandaloneMode, state.kind, engine.status, engine.isServerRunning, restartServer])
+
+    const attempt = crashRestartsRef.current
+    if (!autoRestart || attempt >= AUTO_RESTART_MAX_ATTEMPTS) {
+      const headline = autoRestart
+        ? i18n.t('app.settings.engine.crashedRepeatedly')
+        : i18n.t('app.settings.engine.crashed')
+      log.warn('Standalone server not running while lifecycle state is ready - not restarting after', attempt, 'tries')
+      // A manual restart or fix starts with a fresh budget.
+      crashRestartsRef.current = 0
+      void invoke('get-last-server-exit-tail')
+        .catch(() => null)
+        .then((tail) => setState({ kind: 'failed', error: tail ? `${headline}\n\n${tail}` : headline }))
+      return
+    }
+
+    const delay = Math.min(AUTO_RESTART_BASE_DELAY_MS * 2 ** attempt, AUTO_RESTART_MAX_DELAY_MS)
+    log.warn(`Standalone server not running while lifecycle state is ready - auto-recovering in ${delay}ms`)
+    const timer = setTimeout(() => {
+      crashRestartsRef.current = attempt + 1
+      void restartServer().catch((err) => log.error('Auto-recover restart failed:', errorMessage(err)))
+    }, delay)
+    return () => clearTimeout(timer)
+  }, [isStandaloneMode, state.kind, statusKnown, engine.isServerRunning, autoRestart, restartServer])
+
+  // The main process pushes `engine-crashed` when the managed server exits
+  // on its own. Refreshing status feeds the reconcile effect above, which
+  // does the actual restart; nothing else is needed here.
+  useEffect(() => {
+    if (!isStandaloneMode) return
+    return listen('engine-crashed', ({ exit_code, signal }) => {
+      log.warn('Standalone server crashed', signal ?? `exit code ${exit_code}`)
+      void engine.checkStatus()
+    })
+  }, [isStandaloneMode, engine.checkStatus])
+
+  // Mirror the lifecycle into the tray icon, and route its "Restart
+  // Engine" item through the same pipeline lock as everything else.
+  useEffect(() => {
+    void invoke('set-tray-engine-state', TRAY_ENGINE_STATES[state.kind]).catch(() => null)
+  }, [state.kind])
+
+  useEffect(() => {
+    if (!isStandaloneMode) return
+    return listen('tray-restart-engine', () => {
+      void restartServer().catch((err) => log.error('Tray restart failed:', errorMessage(err)))
+    })
+  }, [isStandaloneMode, restartServer])
 
   // Fires on mount and whenever `isStandaloneMode` flips (e.g. user
   // toggling engine_mode in settings). The `runExclusive` lock handles
@@ -361,6 +438,10 @@ const orchestratePipeline = async (
     return { kind: 'not_installed' }
   }
 
+  if (status.uv_outdated) {
+    log.warn('Installed uv is outdated; the next install or fix will upgrade it:', status.uv_version)
+  }
+
   if (status.server_running) {
     // A previous Biome instance left a managed server alive (most
     // likely a hot-reload during development). Adopt it instead of
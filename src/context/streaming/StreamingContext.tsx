@@ -1,5 +1,6 @@
 import { useState, useEffect, useRef, useCallback, useMemo, type ReactNode } from 'react'
 import { usePortal } from '../portal/portalContextValue'
+import { invoke } from '../../bridge'
 import { TranslatableError } from '../../i18n'
 import useWebSocket, {
   isConnected as wsIsConnected,
@@ -155,7 +156,7 @@ export const StreamingProvider = ({ children }: { children: ReactNode }) => {
     })
   }, [getSeedsDirPath])
 
-  const { selectSeed, lastApplied, resetSession } = useSessionInit({
+  const { selectSeed, currentSeed, lastApplied, resetSession } = useSessionInit({
     portalState: state,
     loadingState: states.LOADING,
     isConnected: wsIsConnected(connectionStatus),
@@ -184,16 +185,34 @@ export const StreamingProvider = ({ children }: { children: ReactNode }) => {
     sceneEdit.dispatch({ type: 'OPEN' })
   }, [exitPointerLock, sceneEdit])
 
+  // Main saves the PNG and announces it with `screenshot-saved`, which
+  // drives the toast.
+  const copyScreenshotToClipboard = settings.screenshots.copy_to_clipboard
+  const handleScreenshot = useCallback(() => {
+    invoke('capture-screenshot', copyScreenshotToClipboard).catch((err) => log.error('Screenshot failed:', err))
+  }, [copyScreenshotToClipboard])
+
+  // Releasing the pointer lets the usual unlock path pause the engine and
+  // open the pause menu; clicking back in resumes as after Esc.
+  const handleIdle = useCallback(() => {
+    log.info('No input for the idle timeout - releasing pointer to pause')
+    exitPointerLock()
+  }, [exitPointerLock])
+
   const { pressedKeys, mouseButtons, pressedGamepad, scrollActive, isPointerLocked } = useInputLoop({
     enabled: inputEnabled,
     containerRef,
     keybindings: settings.keybindings,
     mouseSensitivity: settings.mouse_sensitivity,
     gamepadSensitivity: settings.gamepad_sensitivity,
+    toggleMovement: settings.accessibility.toggle_movement,
     sendControl,
     onReset: handleReset,
     onSceneEdit: settings.scene_authoring_enabled ? handleSceneEdit : null,
-    onExitPointerLock: exitPointerLock
+    onExitPointerLock: exitPointerLock,
+    onScreenshot: handleScreenshot,
+    idleTimeoutMs: settings.display.idle_pause_minutes * 60_000,
+    onIdle: handleIdle
   })
 
   useLoadingFailureCleanup({
@@ -260,7 +279,13 @@ export const StreamingProvider = ({ children }: { children: ReactNode }) => {
     resume
   })
 
-  const { registerCanvas, canvasReady, frameTimelineRef, metricsRef: pacerMetricsRef } = useFramePacer({ batch })
+  const {
+    registerCanvas,
+    captureFrame,
+    canvasReady,
+    frameTimelineRef,
+    metricsRef: pacerMetricsRef
+  } = useFramePacer({ batch })
 
   const registerContainerRef = useCallback((element: HTMLDivElement | null) => {
     containerRef.current = element
@@ -349,10 +374,11 @@ export const StreamingProvider = ({ children }: { children: ReactNode }) => {
   const seedsValue = useMemo<SeedsContextValue>(
     () => ({
       dir: seedsDir,
+      current: currentSeed,
       openDir: openSeedsDir,
       select: selectSeed
     }),
-    [seedsDir, openSeedsDir, selectSeed]
+    [seedsDir, currentSeed, openSeedsDir, selectSeed]
   )
 
   const websocketValue = useMemo<WebsocketContextValue>(
@@ -394,9 +420,10 @@ export const StreamingProvider = ({ children }: { children: ReactNode }) => {
     () => ({
       registerContainer: registerContainerRef,
       registerCanvas,
-      handleContainerClick
+      handleContainerClick,
+      captureFrame
     }),
-    [registerContainerRef, registerCanvas, handleContainerClick]
+    [registerContainerRef, registerCanvas, handleContainerClick, captureFrame]
   )
 
   return (
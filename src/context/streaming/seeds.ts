@@ -6,6 +6,8 @@ import { createStreamingContext } from './createStreamingContext'
  *  `useSeedManager` in the pause UI. */
 export type SeedsContextValue = {
   dir: string | null
+  /** Filename of the seed the session was last started from. */
+  current: string | null
   openDir: () => Promise<void>
   select: (filename: string) => Promise<void>
 }
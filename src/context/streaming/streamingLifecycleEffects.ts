@@ -1,3 +1,4 @@
+import { invoke } from '../../bridge'
 import { TranslatableError } from '../../i18n'
 import type { StreamingLifecycleEffects } from './streamingLifecycleMachine'
 import type { PortalState } from '../portal/portalStateMachine'
@@ -78,6 +79,7 @@ export const createStreamingLifecycleEffectHandlers = ({
       if (isWarmFlowCancelled()) return
       log.error('Connection error during loading state:', info)
       if (info) {
+        void invoke('show-notification', 'loading-failed').catch(() => null)
         if ('key' in info) {
           setEngineError(new TranslatableError(info.key))
         } else {
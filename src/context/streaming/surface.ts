@@ -1,13 +1,14 @@
 import { createStreamingContext } from './createStreamingContext'
 
 /** Imperative handles that wire the gameplay video surface to the
- *  streaming pipeline. Only the `<VideoContainer>` consumes these — it
- *  registers its container/canvas refs at mount and forwards click
- *  events for pointer-lock acquisition. */
+ *  streaming pipeline. `<VideoContainer>` registers its container/canvas
+ *  refs at mount and forwards click events for pointer-lock acquisition;
+ *  `captureFrame` snapshots the canvas (saved-world thumbnails). */
 export type SurfaceContextValue = {
   registerContainer: (element: HTMLDivElement | null) => void
   registerCanvas: (element: HTMLCanvasElement | null) => void
   handleContainerClick: () => void
+  captureFrame: () => string | null
 }
 
 export const { Context: SurfaceContext, use: useStreamingSurface } =
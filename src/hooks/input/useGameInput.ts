@@ -52,7 +52,8 @@ export const GAME_ACTIONS: readonly GameAction[] = [
     keyboard: { bindKey: 'sceneEdit', defaultCode: 'KeyQ' },
     gamepad: { button: 'Y' },
     requiresSceneAuthoring: true
-  }
+  },
+  { id: 'screenshot', keyboard: { bindKey: 'screenshot', defaultCode: 'F2' } }
 ]
 
 export const CONTROLS: readonly Control[] = GAME_ACTIONS.flatMap((a) =>
@@ -203,7 +204,24 @@ Object.assign(CODE_MAP, {
 } satisfies Record<InputCode, ServerCode>)
 
 /** Actions that emit no server code and instead invoke a callback when their binding is pressed. */
-const CALLBACK_ACTIONS = new Set<ControlBindKey>(['pauseMenu', 'resetScene', 'sceneEdit'])
+const CALLBACK_ACTIONS = new Set<ControlBindKey>(['pauseMenu', 'resetScene', 'sceneEdit', 'screenshot'])
+
+/** Hold actions that `toggleMovement` turns into press-to-toggle. */
+const TOGGLE_ACTIONS: readonly ControlBindKey[] = [
+  'moveForward',
+  'moveLeft',
+  'moveBack',
+  'moveRight',
+  'sprint',
+  'crouch'
+]
+
+const toggleCode = (held: Set<InputCode>, code: InputCode): Set<InputCode> => {
+  const next = new Set(held)
+  if (next.has(code)) next.delete(code)
+  else next.add(code)
+  return next
+}
 
 const isEditableTarget = (target: EventTarget | null) =>
   target instanceof HTMLInputElement ||
@@ -262,7 +280,9 @@ export const useGameInput = (
   onReset: (() => void) | null = null,
   keybindings: Keybindings = DEFAULT_KEYBINDINGS,
   onSceneEdit?: (() => void) | null,
-  onPauseMenu?: (() => void) | null
+  onPauseMenu?: (() => void) | null,
+  toggleMovement = false,
+  onScreenshot?: (() => void) | null
 ): UseGameInputResult => {
   const [pressedKeys, setPressedKeys] = useState<Set<InputCode>>(new Set())
   const [mouseButtons, setMouseButtons] = useState<Set<InputCode>>(new Set())
@@ -278,9 +298,9 @@ export const useGameInput = (
    *  For each remappable action we: (a) remove its default input code from the
    *  passthrough map (so the default no longer emits the canonical server code
    *  after a rebind), and (b) bind the user-chosen input code to the action's
-   *  canonical server code. Callback actions (pauseMenu, resetScene, sceneEdit)
-   *  have no canonical server code and are handled via callback, not through
-   *  this map. */
+   *  canonical server code. Callback actions (pauseMenu, resetScene, sceneEdit,
+   *  screenshot) have no canonical server code and are handled via callback,
+   *  not through this map. */
   const effectiveCodeMap = useMemo(() => {
     const map = { ...CODE_MAP }
 
@@ -302,6 +322,13 @@ export const useGameInput = (
     return map
   }, [keybindings])
 
+  /** Input codes latched by `toggleMovement`. Held state for these flips on
+   *  press and ignores release; pointer unlock and blur still clear it. */
+  const latchedCodes = useMemo(
+    () => new Set<InputCode>(toggleMovement ? TOGGLE_ACTIONS.map((key) => keybindings[key]) : []),
+    [toggleMovement, keybindings]
+  )
+
   const handleKeyDown = useCallback(
     (e: KeyboardEvent) => {
       // When game input is active, capture Ctrl/Alt as game buttons.
@@ -316,7 +343,8 @@ export const useGameInput = (
       const callbackHandlers: Array<[ControlBindKey, (() => void) | null | undefined]> = [
         ['resetScene', onReset],
         ['sceneEdit', onSceneEdit],
-        ['pauseMenu', onPauseMenu]
+        ['pauseMenu', onPauseMenu],
+        ['screenshot', onScreenshot]
       ]
       for (const [bindKey, handler] of callbackHandlers) {
         // Skip callbacks with no handler wired (e.g. sceneEdit when the Scene Authoring
@@ -341,10 +369,14 @@ export const useGameInput = (
       // Store the physical InputCode; translation to ServerCode happens in getInputState.
       if (effectiveCodeMap[e.code]) {
         e.preventDefault()
+        if (latchedCodes.has(e.code)) {
+          if (!e.repeat) setPressedKeys((prev) => toggleCode(prev, e.code))
+          return
+        }
         setPressedKeys((prev) => new Set([...prev, e.code]))
       }
     },
-    [enabled, onReset, onSceneEdit, onPauseMenu, keybindings, effectiveCodeMap]
+    [enabled, onReset, onSceneEdit, onPauseMenu, onScreenshot, keybindings, effectiveCodeMap, latchedCodes]
   )
 
   const handleKeyUp = useCallback(
@@ -353,6 +385,7 @@ export const useGameInput = (
       if (!enabled) return
       if (effectiveCodeMap[e.code]) {
         e.preventDefault()
+        if (latchedCodes.has(e.code)) return
         setPressedKeys((prev) => {
           const next = new Set(prev)
           next.delete(e.code)
@@ -360,7 +393,7 @@ export const useGameInput = (
         })
       }
     },
-    [enabled, effectiveCodeMap]
+    [enabled, effectiveCodeMap, latchedCodes]
   )
 
   const handleMouseDown = useCallback(
@@ -373,17 +406,21 @@ export const useGameInput = (
         return
       }
       if (effectiveCodeMap[inputCode]) {
+        if (latchedCodes.has(inputCode)) {
+          setMouseButtons((prev) => toggleCode(prev, inputCode))
+          return
+        }
         setMouseButtons((prev) => new Set([...prev, inputCode]))
       }
     },
-    [enabled, onPauseMenu, keybindings.pauseMenu, effectiveCodeMap]
+    [enabled, onPauseMenu, keybindings.pauseMenu, effectiveCodeMap, latchedCodes]
   )
 
   const handleMouseUp = useCallback(
     (e: MouseEvent) => {
       if (!enabled) return
       const inputCode = MOUSE_BUTTON_TO_CODE[e.button]
-      if (!inputCode) return
+      if (!inputCode || latchedCodes.has(inputCode)) return
       if (effectiveCodeMap[inputCode]) {
         setMouseButtons((prev) => {
           const next = new Set(prev)
@@ -392,7 +429,7 @@ export const useGameInput = (
         })
       }
     },
-    [enabled, effectiveCodeMap]
+    [enabled, effectiveCodeMap, latchedCodes]
   )
 
   const handleMouseMove = useCallback(
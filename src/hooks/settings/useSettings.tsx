@@ -1,5 +1,5 @@
 import { useState, useEffect, useCallback, type ReactNode } from 'react'
-import { invoke } from '../../bridge'
+import { invoke, listen } from '../../bridge'
 import type { Settings } from '../../types/settings'
 import { ENGINE_MODES, DEFAULT_STANDALONE_URL } from '../../types/settings'
 import { SettingsContext, type SettingsContextValue } from './settingsContextValue'
@@ -34,6 +34,10 @@ export const SettingsProvider = ({ children }: { children: ReactNode }) => {
     loadSettings()
   }, [])
 
+  // settings.json edited outside the app (the "open settings file" button
+  // hands it to a text editor). Main validates before pushing.
+  useEffect(() => listen('settings-changed', setSettings), [])
+
   const reloadSettings = useCallback(async () => {
     try {
       const fileSettings = await invoke('read-settings')
@@ -180,6 +180,7 @@ const GAP_EMA_MULTIPLIER = 5
  */
 export function useFramePacer(opts: { batch: FrameBatch | null }): {
   registerCanvas: (element: HTMLCanvasElement | null) => void
+  captureFrame: () => string | null
   canvasReady: boolean
   frameTimelineRef: RefObject<FrameTimeline>
   metricsRef: RefObject<PacerMetrics>
@@ -390,5 +391,13 @@ export function useFramePacer(opts: { batch: FrameBatch | null }): {
     setCanvasReady(!!element)
   }, [])
 
-  return { registerCanvas, canvasReady, frameTimelineRef, metricsRef }
+  /** The frame currently on the canvas as base64 JPEG (no data-URL prefix),
+   *  or null before the first frame is drawn. */
+  const captureFrame = useCallback((): string | null => {
+    const canvas = canvasRef.current
+    if (!canvas || canvas.width === 0 || canvas.height === 0) return null
+    return canvas.toDataURL('image/jpeg', 0.85).split(',')[1] ?? null
+  }, [])
+
+  return { registerCanvas, captureFrame, canvasReady, frameTimelineRef, metricsRef }
 }
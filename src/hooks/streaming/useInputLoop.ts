@@ -14,7 +14,7 @@ type ScrollActive = { up: boolean; down: boolean }
  *
  *  Held inputs (pressedKeys / mouseButtons / pressedGamepad) and
  *  pointer-lock state come from `useGameInput`; this hook just adds
- *  the rAF send loop and the scroll inference on top. */
+ *  the rAF send loop, the scroll inference and the idle timeout on top. */
 export function useInputLoop(opts: {
   /** True when input should flow to the server (streaming +
    *  unpaused + no menu/modal). When false, the rAF loop is suspended
@@ -24,6 +24,8 @@ export function useInputLoop(opts: {
   keybindings: Keybindings
   mouseSensitivity: number
   gamepadSensitivity: number
+  /** Latch movement, sprint and crouch instead of requiring them held. */
+  toggleMovement: boolean
   sendControl: (buttons: ServerCode[], mouseDx: number, mouseDy: number) => boolean
   onReset: () => void
   /** Pass `null` when scene-authoring is disabled so the keybind is
@@ -31,6 +33,11 @@ export function useInputLoop(opts: {
    *  conflict warning). */
   onSceneEdit: (() => void) | null
   onExitPointerLock: () => void
+  onScreenshot: () => void
+  /** How long the loop may run with no buttons held and no look input
+   *  before `onIdle` fires. 0 disables the timeout. */
+  idleTimeoutMs: number
+  onIdle: () => void
 }): {
   pressedKeys: Set<InputCode>
   mouseButtons: Set<InputCode>
@@ -44,10 +51,14 @@ export function useInputLoop(opts: {
     keybindings,
     mouseSensitivity,
     gamepadSensitivity,
+    toggleMovement,
     sendControl,
     onReset,
     onSceneEdit,
-    onExitPointerLock
+    onExitPointerLock,
+    onScreenshot,
+    idleTimeoutMs,
+    onIdle
   } = opts
 
   const { pressedKeys, mouseButtons, pressedGamepad, getInputState, isPointerLocked } = useGameInput(
@@ -56,12 +67,15 @@ export function useInputLoop(opts: {
     onReset,
     keybindings,
     onSceneEdit,
-    onExitPointerLock
+    onExitPointerLock,
+    toggleMovement,
+    onScreenshot
   )
 
   const [scrollActive, setScrollActive] = useState<ScrollActive>({ up: false, down: false })
   const scrollTimeoutRef = useRef<ReturnType<typeof setTimeout> | null>(null)
   const rafRef = useRef<number | null>(null)
+  const lastActivityRef = useRef(0)
 
   useEffect(() => {
     if (!enabled) {
@@ -72,6 +86,7 @@ export function useInputLoop(opts: {
       return
     }
 
+    lastActivityRef.current = performance.now()
     const tick = () => {
       const { buttons, mouse, gamepad } = getInputState()
       const scrollUp = buttons.includes('SCROLL_UP')
@@ -84,6 +99,14 @@ export function useInputLoop(opts: {
       const dx = mouse.dx * mouseSensitivity + gamepad.dx * gamepadSensitivity
       const dy = mouse.dy * mouseSensitivity + gamepad.dy * gamepadSensitivity
       sendControl(buttons, Math.round(dx), Math.round(dy))
+
+      const now = performance.now()
+      if (buttons.length > 0 || dx !== 0 || dy !== 0) {
+        lastActivityRef.current = now
+      } else if (idleTimeoutMs > 0 && now - lastActivityRef.current >= idleTimeoutMs) {
+        lastActivityRef.current = now
+        onIdle()
+      }
       rafRef.current = requestAnimationFrame(tick)
     }
     rafRef.current = requestAnimationFrame(tick)
@@ -98,7 +121,7 @@ export function useInputLoop(opts: {
         scrollTimeoutRef.current = null
       }
     }
-  }, [enabled, getInputState, sendControl, mouseSensitivity, gamepadSensitivity])
+  }, [enabled, getInputState, sendControl, mouseSensitivity, gamepadSensitivity, idleTimeoutMs, onIdle])
 
   return { pressedKeys, mouseButtons, pressedGamepad, scrollActive, isPointerLocked }
 }
@@ -56,6 +56,7 @@ export function useSessionInit(opts: {
   setPlaceholderFrame: (frame: Blob | string | null) => void
 }): {
   selectSeed: (filename: string) => Promise<void>
+  currentSeed: string | null
   lastApplied: RestartSignatures | null
   resetSession: () => void
 } {
@@ -73,6 +74,8 @@ export function useSessionInit(opts: {
   } = opts
 
   const lastSeedRef = useRef<{ filename: string; imageData: string } | null>(null)
+  // Render-visible mirror of `lastSeedRef.current.filename`.
+  const [currentSeed, setCurrentSeed] = useState<string | null>(null)
   const warmBootstrapSentRef = useRef(false)
   const [lastApplied, setLastApplied] = useState<RestartSignatures | null>(null)
 
@@ -102,6 +105,7 @@ export function useSessionInit(opts: {
     const selectedModel = settings?.engine_model || DEFAULT_ENGINE_MODEL
     const seedFilename = lastSeedRef.current?.filename ?? 'default.jpg'
     log.info('Loading connected - bootstrapping session with model+seed:', selectedModel, seedFilename)
+    setCurrentSeed(seedFilename)
 
     const bootstrap = async () => {
       let imageData = lastSeedRef.current?.imageData
@@ -191,6 +195,7 @@ export function useSessionInit(opts: {
       const result = await invoke('get-seed-image-base64', filename)
       if (!result) return
       lastSeedRef.current = { filename, imageData: result.base64 }
+      setCurrentSeed(filename)
       const config = await buildSessionConfig(settingsRef.current, isStandaloneMode)
       const metrics = await sendInit({
         model: settingsRef.current.engine_model || DEFAULT_ENGINE_MODEL,
@@ -208,5 +213,5 @@ export function useSessionInit(opts: {
     setLastApplied(null)
   }, [])
 
-  return { selectSeed, lastApplied, resetSession }
+  return { selectSeed, currentSeed, lastApplied, resetSession }
 }
@@ -32,7 +32,10 @@ const en = {
         copy: 'Copy',
         open: 'Open',
         browseForImageFile: 'Browse for image file',
-        delete: 'Delete'
+        delete: 'Delete',
+        launch: 'Launch',
+        savedWorlds: 'Saved Worlds',
+        restartEngine: 'Restart Engine'
       },
       dialogs: {
         updateAvailable: {
@@ -43,6 +46,15 @@ const en = {
           title: 'Connection Lost',
           description: 'The connection to the engine was lost. Would you like to try reconnecting?'
         },
+        deepLinkConnect: {
+          title: 'Join Server?',
+          description:
+            'This link wants to switch Biome to server mode and connect to {{url}}. Only continue if you trust whoever shared it.'
+        },
+        deepLinkWorld: {
+          title: 'Load World?',
+          description: 'This link wants to start a session with the seed {{seed}}.'
+        },
         install: {
           title: 'Installation',
           installing: 'Installing...',
@@ -56,6 +68,10 @@ const en = {
           abortEngineInstall: 'Abort engine install',
           closeInstallLogs: 'Close install logs'
         },
+        restartForHfToken: {
+          title: 'Restart the Engine?',
+          description: 'The running engine still has the previous HuggingFace token. Restart it now to use the new one?'
+        },
         fixInPlace: {
           title: 'Fix In Place?',
           description:
@@ -85,6 +101,28 @@ const en = {
           confirmDeleteDescription: 'Delete <bold>{{filename}}</bold>? This cannot be undone.',
           openExternally: 'Open'
         },
+        savedWorlds: {
+          title: 'Saved Worlds',
+          description: 'Save the world you are in to come back to it later.',
+          saveCurrent: 'Save current world',
+          empty: 'No saved worlds yet. Save the world you are in to find it here.',
+          load: 'Load',
+          seedMissing: 'The scene image for this world no longer exists.',
+          failed: 'Something went wrong. Check the logs for details.',
+          share: 'Share',
+          importFile: 'Import world file',
+          shared: 'Saved to {{path}}',
+          exportDialogTitle: 'Save world file',
+          importDialogTitle: 'Open world file',
+          worldFileType: 'Biome world file',
+          notWorldFile: '{{file}} is not a Biome world file.',
+          versionMismatch: '{{file}} was made with Biome {{fileVersion}}; this is Biome {{appVersion}}.',
+          worldFileSeedMissing: '{{file}} is missing its scene image.',
+          wrongExtension: 'World files must be saved as .{{extension}} files.',
+          worldGone: 'This saved world no longer exists.',
+          confirmDeleteTitle: 'Delete saved world?',
+          confirmDeleteDescription: 'Delete <bold>{{name}}</bold>? This cannot be undone.'
+        },
         serverUnreachable: {
           title: 'Server Unreachable',
           withUrl:
@@ -166,6 +204,47 @@ const en = {
           description: 'which language should Biome use?',
           system: 'System Default'
         },
+        updateChannel: {
+          title: 'Updates',
+          description: 'which releases should Biome tell you about?',
+          stable: 'Stable',
+          beta: 'Beta (Prereleases)'
+        },
+        settingsBackup: {
+          title: 'Settings Backup',
+          description: 'move your setup to another machine or share it',
+          file: 'Settings file',
+          export: 'Export...',
+          import: 'Import...',
+          importFailed: "Couldn't import settings. Check the app log for details."
+        },
+        tray: {
+          title: 'System Tray',
+          description: 'keep Biome running in the background',
+          enabled: 'Minimize to Tray',
+          enabledDescription:
+            'Closing the window hides Biome to the tray and keeps the engine running. Quit from the tray menu.'
+        },
+        notifications: {
+          title: 'Notifications',
+          description: 'get told when long operations finish',
+          enabled: 'Desktop Notifications',
+          enabledDescription:
+            'Notify when engine setup finishes, a world fails to load, or the engine crashes while Biome is in the background.'
+        },
+        display: {
+          title: 'Display',
+          description: 'how the window behaves while you play',
+          sessionFullscreen: 'Fullscreen While Playing',
+          sessionFullscreenDescription:
+            'Go fullscreen and keep the screen awake for each session. The window returns to its previous size when you leave.',
+          monitor: 'Monitor',
+          currentMonitor: 'Current Monitor',
+          primary: 'primary',
+          idlePause: 'Pause When Idle',
+          idlePauseNever: 'Never',
+          idlePauseMinutes: 'After {{minutes}} min'
+        },
         engineMode: {
           title: 'Mode',
           description: 'where will the engine run? as part of Biome, or elsewhere?',
@@ -180,7 +259,66 @@ const en = {
           connected: 'connected',
           unreachable: 'unreachable',
           ownManaged: "Biome's built-in server",
-          placeholder: 'http://localhost:7987'
+          placeholder: 'http://localhost:7987',
+          profiles: 'Saved servers',
+          saveProfile: 'Save this server',
+          removeProfile: 'Remove from saved servers'
+        },
+        hfToken: {
+          title: 'HuggingFace Token',
+          description: 'needed for gated models; stored in your OS keychain',
+          token: 'Access token',
+          placeholder: 'hf_...',
+          checking: 'checking...',
+          saved: 'saved · signed in as {{username}}',
+          clear: 'Clear',
+          rejected: 'HuggingFace rejected this token.',
+          unverifiedOffline: 'saved · not checked because Offline Mode is on',
+          unverifiedHttp: 'saved · not checked, HuggingFace returned HTTP {{status}}',
+          unverifiedNetwork: "saved · not checked, couldn't reach HuggingFace",
+          saveFailed: "Couldn't save the token. Check the app log for details."
+        },
+        issues: {
+          invalidJson: "{{file}} isn't a valid JSON file.",
+          invalidValue: 'Invalid value for {{path}}.',
+          invalidUrl: '"{{value}}" is not a valid URL.',
+          unsupportedProtocol: 'Unsupported protocol "{{protocol}}". Use http, https, ws or wss.',
+          missingHost: 'The URL has no host.',
+          emptyProfileName: 'The profile name is empty.',
+          directoryMissing: 'The folder {{path}} does not exist.',
+          notADirectory: '{{path}} is not a folder.'
+        },
+        offlineInstall: {
+          title: 'Offline Install',
+          description: 'setting up a machine with no internet?',
+          uvArchive: 'uv Archive',
+          uvArchiveHint: 'The uv release archive for this platform, downloaded on a connected machine.',
+          uvCache: 'Package Cache',
+          uvCacheHint:
+            'The .uv/cache folder from a machine that finished an online install. Turn on Offline Mode, then install the engine.',
+          choose: 'Choose...',
+          uvArchiveDialogTitle: 'Choose the uv release archive',
+          uvCacheDialogTitle: 'Choose a uv cache folder',
+          wrongArchive: 'Expected the uv release archive for this platform ({{expected}}), got {{got}}.',
+          notFound: 'File not found: {{path}}',
+          notACache: "{{path}} doesn't look like a uv cache folder (no CACHEDIR.TAG).",
+          uvInstalled: 'Installed uv {{version}}.',
+          cacheImported: 'Imported the package cache.'
+        },
+        engineLaunch: {
+          title: 'Launch Options',
+          description: 'how the local engine process is started',
+          gpuDevice: 'GPU',
+          gpuDeviceHint: 'Device index for CUDA_VISIBLE_DEVICES. Empty uses the default GPU.',
+          extraArgs: 'Extra Arguments',
+          extraArgsHint:
+            'Appended to the server command line, separated by spaces. Quotes are not supported, so an argument cannot contain a space.',
+          extraEnv: 'Extra Environment',
+          extraEnvHint:
+            'KEY=VALUE pairs separated by spaces. Quotes are not supported, so a value cannot contain a space.',
+          preview: 'Command',
+          autoRestart: 'Restart After Crash',
+          autoRestartDescription: 'Restarts the engine if it exits unexpectedly, waiting longer after each crash.'
         },
         engine: {
           title: 'Local Engine',
@@ -198,6 +336,11 @@ const en = {
           notInstalledTooltip: 'Install the engine to change this',
           startingTooltip: 'Wait for the engine to finish starting',
           failedTooltip: 'Fix the engine to change this',
+          crashed: 'The engine stopped unexpectedly.',
+          crashedRepeatedly: 'The engine kept crashing, so Biome stopped restarting it.',
+          installRunning: 'An engine install is already running.',
+          installRunningElsewhere:
+            'Another Biome process is installing or updating the engine. Wait for it to finish, then try again.',
           viewLogs: 'view logs'
         },
         performance: {
@@ -245,6 +388,13 @@ const en = {
           soundEffects: 'sound effects',
           music: 'music'
         },
+        accessibility: {
+          title: 'Accessibility',
+          description: 'assists for hard-to-hold controls',
+          toggleMovement: 'Toggle Movement',
+          toggleMovementDescription:
+            'Press a movement, sprint or crouch key once to start and again to stop, instead of holding it.'
+        },
         mouseSensitivity: {
           title: 'Mouse Sensitivity',
           description: 'how much should the camera move when you move your mouse?',
@@ -293,7 +443,8 @@ const en = {
             secondaryFire: 'Secondary Fire',
             pauseMenu: 'Pause Menu',
             resetScene: 'Reset Scene',
-            sceneEdit: 'Scene Edit'
+            sceneEdit: 'Scene Edit',
+            screenshot: 'Screenshot'
           }
         },
         offlineMode: {
@@ -303,6 +454,20 @@ const en = {
           enabledDescription:
             "You can keep using what's already set up, but engine reinstalls and model downloads will fail."
         },
+        network: {
+          title: 'Network',
+          description: 'behind a proxy, or need mirrors for blocked downloads?',
+          proxy: 'Proxy',
+          proxyHint: "Used for Biome's own downloads and by the engine. Empty uses the system settings.",
+          uvMirror: 'uv Mirror',
+          uvMirrorHint: 'Replaces the GitHub releases host for uv downloads.',
+          pypiMirror: 'Package Index',
+          pypiMirrorHint: 'Replaces PyPI as the default package index.',
+          pythonMirror: 'Python Mirror',
+          pythonMirrorHint: 'Replaces the download host for managed Python builds.',
+          hfMirror: 'HuggingFace Mirror',
+          hfMirrorHint: 'Replaces the HuggingFace hub endpoint for models and token checks.'
+        },
         sceneAuthoring: {
           title: 'Scene Authoring',
           description: 'want to compose and modify scenes with text prompts?',
@@ -311,7 +476,19 @@ const en = {
             'Generate new scenes or edit the current one with a text prompt, powered by a local image model. Requires 8-10 GB additional VRAM.',
           saveGenerated: 'Save Generated Scenes',
           saveGeneratedDescription:
-            'Keep every generated scene in your Scenes list so you can revisit or delete it later.'
+            'Keep every generated scene in your Scenes list so you can revisit or delete it later.',
+          promptHistory: 'Prompt History',
+          promptHistoryDescription: 'Prompts you have used are suggested as you type a new one.',
+          clearPromptHistory: 'Clear',
+          promptHistoryCleared: 'Cleared'
+        },
+        screenshots: {
+          title: 'Screenshots',
+          description: 'want to keep a moment?',
+          copyToClipboard: 'Copy to Clipboard',
+          copyToClipboardDescription: 'Also put each screenshot on the clipboard, ready to paste.',
+          folder: 'Screenshots Folder',
+          folderDescription: 'Press the screenshot key while playing to save a PNG here.'
         },
         recording: {
           title: 'Video Recording',
@@ -338,6 +515,14 @@ const en = {
             "Record all inputs to a file on the server for replay. Written to the OS's temp directory.",
           diagnostics: 'Diagnostics',
           diagnosticsDescription: 'Copy diagnostic information to the clipboard for bug reports.',
+          appLog: 'App Log',
+          appLogDescription: 'Copy the last {{count}} lines of the app log, including previous runs. Saved at {{path}}',
+          crashReports: 'Crash Reports',
+          crashReportsDescription: '{{count}} from earlier runs. Attach the newest to a bug report.',
+          crashReportsNone: 'No crashes recorded.',
+          crashKindReport: 'Report',
+          crashKindMinidump: 'Native crash dump',
+          showCrashReport: 'Show',
           copiedToClipboard: 'Copied to clipboard',
           copyFailed: 'Failed to copy'
         },
@@ -367,6 +552,30 @@ const en = {
           placeholder: 'What do you want to play?'
         }
       },
+      screenshots: {
+        saved: 'Screenshot saved',
+        savedAndCopied: 'Screenshot saved and copied'
+      },
+      tray: {
+        engineStopped: 'Engine: stopped',
+        engineStarting: 'Engine: starting…',
+        engineReady: 'Engine: ready',
+        engineError: 'Engine: error',
+        tooltip: 'Biome — {{state}}',
+        show: 'Show Biome',
+        restartEngine: 'Restart Engine',
+        openConfig: 'Open Config',
+        resetWindowPosition: 'Reset Window Position',
+        quit: 'Quit'
+      },
+      notifications: {
+        setupComplete: 'Engine setup complete',
+        setupFailed: 'Engine setup failed',
+        engineCrashed: 'Engine crashed',
+        loadingFailed: 'World failed to load',
+        exitCode: 'Exit code {{code}}',
+        exitSignal: 'Killed by {{signal}}'
+      },
       scenes: {
         failedToReadImageData: 'Failed to read image data',
         noImageInClipboard: 'No image found in clipboard'
@@ -32,7 +32,10 @@ const goose = {
         copy: 'Copy',
         open: 'Open',
         browseForImageFile: 'Browse for image file',
-        delete: 'Delete'
+        delete: 'Delete',
+        launch: 'Launch',
+        savedWorlds: 'Saved Ponds',
+        restartEngine: 'Wake The Engine Again'
       },
       dialogs: {
         updateAvailable: {
@@ -44,6 +47,15 @@ const goose = {
           title: 'Connection Lost',
           description: 'The connection to the engine was lost. Waddle back and try reconnecting?'
         },
+        deepLinkConnect: {
+          title: 'Join Server?',
+          description:
+            'This link wants to switch Biome to server mode and connect to {{url}}. Only continue if you trust whoever shared it. Honk responsibly.'
+        },
+        deepLinkWorld: {
+          title: 'Load World?',
+          description: 'This link wants to start a session with the seed {{seed}}. A fine place to nest.'
+        },
         install: {
           title: 'Installation',
           installing: 'Building nest...',
@@ -57,6 +69,11 @@ const goose = {
           abortEngineInstall: 'Abort engine install',
           closeInstallLogs: 'Close install logs'
         },
+        restartForHfToken: {
+          title: 'Restart the Engine?',
+          description:
+            'The engine is still holding the old HuggingFace token in its beak. Restart it now to use the new one?'
+        },
         fixInPlace: {
           title: 'Preen In Place?',
           description:
@@ -86,6 +103,29 @@ const goose = {
           confirmDeleteDescription: 'Delete <bold>{{filename}}</bold>? Once gone, it cannot be un-honked.',
           openExternally: 'Open'
         },
+        savedWorlds: {
+          title: 'Saved Ponds',
+          description: 'Tuck this pond away to paddle back to it later.',
+          saveCurrent: 'Save this pond',
+          empty: 'No ponds saved yet. Save the one you are paddling in to find it here.',
+          load: 'Paddle in',
+          seedMissing: 'The picture for this pond has flown away.',
+          failed: 'Something went wrong. Check the logs for details, then honk.',
+          share: 'Share with the flock',
+          importFile: 'Import a pond file',
+          shared: 'Dropped at {{path}}',
+          exportDialogTitle: 'Save pond file',
+          importDialogTitle: 'Open pond file',
+          worldFileType: 'Biome pond file',
+          notWorldFile: '{{file}} is not a Biome pond file. Suspicious honk.',
+          versionMismatch:
+            '{{file}} was made with Biome {{fileVersion}}; this is Biome {{appVersion}}. Different flock.',
+          worldFileSeedMissing: '{{file}} is missing its picture.',
+          wrongExtension: 'Pond files must be saved as .{{extension}} files.',
+          worldGone: 'This pond has already flown away.',
+          confirmDeleteTitle: 'Forget this pond?',
+          confirmDeleteDescription: 'Delete <bold>{{name}}</bold>? Once gone, it cannot be un-honked.'
+        },
         serverUnreachable: {
           title: 'Server Unreachable',
           withUrl:
@@ -167,6 +207,47 @@ const goose = {
           description: 'which language should Biome speak?',
           system: 'System Default'
         },
+        updateChannel: {
+          title: 'Migrations',
+          description: 'which flocks should Biome follow south?',
+          stable: 'Stable',
+          beta: 'Beta (Early Birds)'
+        },
+        settingsBackup: {
+          title: 'Nest Backup',
+          description: 'carry your nest to another pond, or share it with the flock',
+          file: 'Nest file',
+          export: 'Export...',
+          import: 'Import...',
+          importFailed: "Couldn't carry those settings into the nest. Check the app log, then honk."
+        },
+        tray: {
+          title: 'System Tray',
+          description: 'keep Biome running in the background',
+          enabled: 'Minimize to Tray',
+          enabledDescription:
+            'Closing the window hides Biome to the tray and keeps the engine running. Quit from the tray menu. The goose never truly leaves.'
+        },
+        notifications: {
+          title: 'Notifications',
+          description: 'get told when long operations finish',
+          enabled: 'Desktop Notifications',
+          enabledDescription:
+            'Notify when engine setup finishes, a world fails to load, or the engine crashes while Biome is in the background. A honk from afar.'
+        },
+        display: {
+          title: 'Display',
+          description: 'how the window behaves while you play',
+          sessionFullscreen: 'Fullscreen While Playing',
+          sessionFullscreenDescription:
+            'Go fullscreen and keep the screen awake for each session. The window returns to its previous size when you leave. The whole pond, edge to edge.',
+          monitor: 'Monitor',
+          currentMonitor: 'Current Monitor',
+          primary: 'primary',
+          idlePause: 'Nap When Idle',
+          idlePauseNever: 'Never',
+          idlePauseMinutes: 'After {{minutes}} min'
+        },
         engineMode: {
           title: 'Mode',
           description: 'where will the goose run? as part of Biome, or borrowed from the flock?',
@@ -181,7 +262,67 @@ const goose = {
           connected: 'connected',
           unreachable: 'unreachable',
           ownManaged: "Biome's home pond",
-          placeholder: 'http://localhost:7987'
+          placeholder: 'http://localhost:7987',
+          profiles: 'Saved ponds',
+          saveProfile: 'Remember this pond',
+          removeProfile: 'Forget this pond'
+        },
+        hfToken: {
+          title: 'HuggingFace Token',
+          description: 'for the fenced-off ponds; tucked safely under your wing',
+          token: 'Access token',
+          placeholder: 'hf_...',
+          checking: 'checking...',
+          saved: 'saved · honking as {{username}}',
+          clear: 'Clear',
+          rejected: 'HuggingFace hissed at this token.',
+          unverifiedOffline: 'tucked away · not checked, the goose is grounded (Offline Mode)',
+          unverifiedHttp: 'tucked away · not checked, HuggingFace honked back HTTP {{status}}',
+          unverifiedNetwork: "tucked away · not checked, couldn't fly to HuggingFace",
+          saveFailed: "Couldn't tuck the token away. Check the app log, then honk."
+        },
+        issues: {
+          invalidJson: '{{file}} is not JSON any goose can read.',
+          invalidValue: '{{path}} got a funny-looking value. Honk.',
+          invalidUrl: '"{{value}}" is not a URL any goose would follow.',
+          unsupportedProtocol: 'The goose only flies http, https, ws or wss, not "{{protocol}}".',
+          missingHost: 'This URL has no host to land on.',
+          emptyProfileName: 'The profile needs a name before it can join the flock.',
+          directoryMissing: 'The nest {{path}} does not exist.',
+          notADirectory: '{{path}} is not a nest (folder).'
+        },
+        offlineInstall: {
+          title: 'Grounded Install',
+          description: 'building a nest with no internet?',
+          uvArchive: 'uv Archive',
+          uvArchiveHint: 'The uv release archive for this platform, fetched by a goose with internet.',
+          uvCache: 'Package Cache',
+          uvCacheHint:
+            'The .uv/cache folder from a nest that finished an online install. Turn on Offline Mode, then install the engine.',
+          choose: 'Choose...',
+          uvArchiveDialogTitle: 'Choose the uv release archive',
+          uvCacheDialogTitle: 'Choose a uv cache folder',
+          wrongArchive: 'This pond needs the uv archive for this platform ({{expected}}), not {{got}}.',
+          notFound: 'No file at {{path}}. Honk?',
+          notACache: "{{path}} doesn't look like a uv cache folder (no CACHEDIR.TAG).",
+          uvInstalled: 'uv {{version}} has joined the flock.',
+          cacheImported: 'Package cache tucked into the nest.'
+        },
+        engineLaunch: {
+          title: 'Launch Options',
+          description: 'how the local engine process is started',
+          gpuDevice: 'GPU',
+          gpuDeviceHint: 'Device index for CUDA_VISIBLE_DEVICES. Empty uses the default GPU. Pick your pond.',
+          extraArgs: 'Extra Arguments',
+          extraArgsHint:
+            'Appended to the server command line, separated by spaces. Quotes are not supported, so an argument cannot contain a space.',
+          extraEnv: 'Extra Environment',
+          extraEnvHint:
+            'KEY=VALUE pairs separated by spaces. Quotes are not supported, so a value cannot contain a space.',
+          preview: 'Command',
+          autoRestart: 'Get Back Up After A Tumble',
+          autoRestartDescription:
+            'Picks the engine back up if it flops over, waiting a little longer after each tumble.'
         },
         engine: {
           title: 'Local Goose',
@@ -199,6 +340,11 @@ const goose = {
           notInstalledTooltip: 'Hatch the engine to change this',
           startingTooltip: 'Wait for the engine to finish hatching',
           failedTooltip: 'Preen the engine to change this',
+          crashed: 'The engine waddled off unexpectedly.',
+          crashedRepeatedly: 'The engine kept tumbling, so Biome stopped picking it up. Honk.',
+          installRunning: 'The engine nest is already being built. Patience, goose.',
+          installRunningElsewhere:
+            'Another goose is already building this nest. Wait for it to finish, then try again.',
           viewLogs: 'peek at the nest'
         },
         performance: {
@@ -246,6 +392,13 @@ const goose = {
           soundEffects: 'sound effects',
           music: 'music'
         },
+        accessibility: {
+          title: 'Accessibility',
+          description: 'assists for hard-to-hold controls',
+          toggleMovement: 'Toggle Movement',
+          toggleMovementDescription:
+            'Press a movement, sprint or crouch key once to start and again to stop, instead of holding it. The goose keeps waddling for you.'
+        },
         mouseSensitivity: {
           title: 'Mouse Sensitivity',
           description: 'how much should the camera turn when you move your mouse?',
@@ -294,7 +447,8 @@ const goose = {
             secondaryFire: 'Hiss',
             pauseMenu: 'Pause Menu',
             resetScene: 'Fresh Pond',
-            sceneEdit: 'Scene Edit'
+            sceneEdit: 'Scene Edit',
+            screenshot: 'Snapshot'
           }
         },
         offlineMode: {
@@ -304,6 +458,20 @@ const goose = {
           enabledDescription:
             "Keep paddling with whatever's already in the nest, but engine reinstalls and model downloads will honk and fail."
         },
+        network: {
+          title: 'Flight Path',
+          description: 'flying through a proxy, or need a different pond for blocked downloads?',
+          proxy: 'Proxy',
+          proxyHint: "Used for Biome's own downloads and by the engine. Empty follows the system flock.",
+          uvMirror: 'uv Mirror',
+          uvMirrorHint: 'Replaces the GitHub releases pond for uv downloads.',
+          pypiMirror: 'Package Index',
+          pypiMirrorHint: 'Replaces PyPI as the default package pond.',
+          pythonMirror: 'Python Mirror',
+          pythonMirrorHint: 'Replaces the download pond for managed Python builds.',
+          hfMirror: 'HuggingFace Mirror',
+          hfMirrorHint: 'Replaces the HuggingFace hub for models and token checks.'
+        },
         sceneAuthoring: {
           title: 'Pond Authoring',
           description: 'want to honk new ponds into shape with text prompts?',
@@ -312,7 +480,19 @@ const goose = {
             'Honk up a fresh pond or edit the current one with a text prompt, powered by a local image model. Requires 8-10 GB additional VRAM.',
           saveGenerated: 'Save Generated Ponds',
           saveGeneratedDescription:
-            'Keep every generated pond in your Ponds list so you can revisit or waddle away from it later.'
+            'Keep every generated pond in your Ponds list so you can revisit or waddle away from it later.',
+          promptHistory: 'Honk History',
+          promptHistoryDescription: 'Past prompts are suggested as you type a new one, like breadcrumbs on the path.',
+          clearPromptHistory: 'Clear',
+          promptHistoryCleared: 'Cleared'
+        },
+        screenshots: {
+          title: 'Snapshots',
+          description: 'want to keep a moment for the scrapbook?',
+          copyToClipboard: 'Copy to Clipboard',
+          copyToClipboardDescription: 'Also drop each snapshot on the clipboard, ready to paste and honk about.',
+          folder: 'Snapshot Nest',
+          folderDescription: 'Press the snapshot key while flying to save a PNG here.'
         },
         recording: {
           title: 'Pond Footage',
@@ -339,6 +519,15 @@ const goose = {
             "Record all inputs to a file on the server for replay. Written to the OS's temp directory.",
           diagnostics: 'Diagnostics',
           diagnosticsDescription: 'Copy diagnostic information to the clipboard for bug reports.',
+          appLog: 'Flight Log',
+          appLogDescription:
+            'Copy the last {{count}} lines of the flight log, earlier flights included. Nested at {{path}}',
+          crashReports: 'Crash Landings',
+          crashReportsDescription: '{{count}} from earlier flights. Attach the newest to a bug report, then honk.',
+          crashReportsNone: 'No crash landings recorded.',
+          crashKindReport: 'Report',
+          crashKindMinidump: 'Native crash dump',
+          showCrashReport: 'Show',
           copiedToClipboard: 'Copied to clipboard',
           copyFailed: 'Failed to copy'
         },
@@ -368,6 +557,30 @@ const goose = {
           placeholder: 'Where shall the goose waddle?'
         }
       },
+      screenshots: {
+        saved: 'Snapshot tucked away',
+        savedAndCopied: 'Snapshot tucked away and copied'
+      },
+      tray: {
+        engineStopped: 'Engine: napping',
+        engineStarting: 'Engine: waddling up…',
+        engineReady: 'Engine: ready to honk',
+        engineError: 'Engine: feathers ruffled',
+        tooltip: 'Biome — {{state}}',
+        show: 'Show Biome',
+        restartEngine: 'Wake The Engine Again',
+        openConfig: 'Open Nest Config',
+        resetWindowPosition: 'Herd Window Back',
+        quit: 'Fly Away'
+      },
+      notifications: {
+        setupComplete: 'Engine nest is ready',
+        setupFailed: 'Engine nest-building failed',
+        engineCrashed: 'Engine took a tumble',
+        loadingFailed: 'World wandered off while loading',
+        exitCode: 'Exit honk {{code}}',
+        exitSignal: 'Plucked by {{signal}}'
+      },
       scenes: {
         failedToReadImageData: 'Failed to read image data',
         noImageInClipboard: 'No image found in clipboard'
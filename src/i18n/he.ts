@@ -32,7 +32,10 @@ const he = {
         copy: 'העתק',
         open: 'פתח',
         browseForImageFile: 'בחר קובץ תמונה',
-        delete: 'מחק'
+        delete: 'מחק',
+        launch: 'הפעל',
+        savedWorlds: 'עולמות שמורים',
+        restartEngine: 'הפעל מחדש את המנוע'
       },
       dialogs: {
         updateAvailable: {
@@ -43,6 +46,15 @@ const he = {
           title: 'החיבור נותק',
           description: 'החיבור למנוע נותק. לנסות להתחבר מחדש?'
         },
+        deepLinkConnect: {
+          title: 'להצטרף לשרת?',
+          description:
+            'הקישור הזה מבקש להעביר את Biome למצב שרת ולהתחבר אל {{url}}. המשך רק אם אתה סומך על מי ששיתף אותו.'
+        },
+        deepLinkWorld: {
+          title: 'לטעון עולם?',
+          description: 'הקישור הזה מבקש להתחיל סשן עם הזרע {{seed}}.'
+        },
         install: {
           title: 'התקנה',
           installing: 'מתקין...',
@@ -56,6 +68,10 @@ const he = {
           abortEngineInstall: 'בטל התקנת מנוע',
           closeInstallLogs: 'סגור לוגי התקנה'
         },
+        restartForHfToken: {
+          title: 'להפעיל מחדש את המנוע?',
+          description: 'המנוע הפועל עדיין משתמש באסימון HuggingFace הקודם. להפעיל אותו מחדש עכשיו כדי להשתמש בחדש?'
+        },
         fixInPlace: {
           title: 'לתקן במקום?',
           description: 'זה יסנכרן מחדש את תלויות המנוע בלי למחוק דבר. בדרך כלל זה מספיק כדי לפתור בעיות אחרי עדכון.'
@@ -83,6 +99,28 @@ const he = {
           confirmDeleteDescription: 'למחוק את <bold>{{filename}}</bold>? לא ניתן לבטל פעולה זו.',
           openExternally: 'פתח'
         },
+        savedWorlds: {
+          title: 'עולמות שמורים',
+          description: 'שמור את העולם שבו אתה נמצא כדי לחזור אליו מאוחר יותר.',
+          saveCurrent: 'שמור את העולם הנוכחי',
+          empty: 'אין עדיין עולמות שמורים. שמור את העולם שבו אתה נמצא כדי למצוא אותו כאן.',
+          load: 'טען',
+          seedMissing: 'תמונת הסצנה של עולם זה כבר לא קיימת.',
+          failed: 'משהו השתבש. בדוק את היומנים לפרטים.',
+          share: 'שתף',
+          importFile: 'ייבא קובץ עולם',
+          shared: 'נשמר ב-{{path}}',
+          exportDialogTitle: 'שמירת קובץ עולם',
+          importDialogTitle: 'פתיחת קובץ עולם',
+          worldFileType: 'קובץ עולם של Biome',
+          notWorldFile: '{{file}} אינו קובץ עולם של Biome.',
+          versionMismatch: '{{file}} נוצר ב-Biome {{fileVersion}}; זוהי Biome {{appVersion}}.',
+          worldFileSeedMissing: 'בקובץ {{file}} חסרה תמונת הסצנה.',
+          wrongExtension: 'יש לשמור קובצי עולם כקובצי .{{extension}}.',
+          worldGone: 'העולם השמור הזה כבר לא קיים.',
+          confirmDeleteTitle: 'למחוק את העולם השמור?',
+          confirmDeleteDescription: 'למחוק את <bold>{{name}}</bold>? לא ניתן לבטל פעולה זו.'
+        },
         serverUnreachable: {
           title: 'אי אפשר להגיע לשרת',
           withUrl: 'לא ניתן להתחבר אל {{url}}. ייתכן שהשרת כבוי, שהכתובת שגויה, או שחומת אש חוסמת את החיבור.',
@@ -159,6 +197,44 @@ const he = {
           description: 'באיזו שפה Biome צריך להשתמש?',
           system: 'ברירת מחדל של המערכת'
         },
+        updateChannel: {
+          title: 'עדכונים',
+          description: 'על אילו גרסאות Biome צריך להודיע לך?',
+          stable: 'יציבה',
+          beta: 'בטא (גרסאות מקדימות)'
+        },
+        settingsBackup: {
+          title: 'גיבוי הגדרות',
+          description: 'העבר את ההגדרות שלך למחשב אחר או שתף אותן',
+          file: 'קובץ הגדרות',
+          export: 'ייצוא...',
+          import: 'ייבוא...',
+          importFailed: 'לא ניתן לייבא את ההגדרות. בדוק את יומן האפליקציה לפרטים.'
+        },
+        tray: {
+          title: 'מגש המערכת',
+          description: 'השאר את Biome פועל ברקע',
+          enabled: 'מזער למגש',
+          enabledDescription: 'סגירת החלון מסתירה את Biome במגש והמנוע ממשיך לפעול. ניתן לצאת מתפריט המגש.'
+        },
+        notifications: {
+          title: 'התראות',
+          description: 'קבל הודעה כשפעולות ארוכות מסתיימות',
+          enabled: 'התראות שולחן עבודה',
+          enabledDescription: 'הצג התראה כשהתקנת המנוע מסתיימת, כשטעינת עולם נכשלת או כשהמנוע קורס בזמן ש-Biome ברקע.'
+        },
+        display: {
+          title: 'תצוגה',
+          description: 'איך החלון מתנהג בזמן משחק',
+          sessionFullscreen: 'מסך מלא בזמן משחק',
+          sessionFullscreenDescription: 'עבור למסך מלא ומנע כיבוי מסך במהלך כל סשן. החלון חוזר לגודלו הקודם כשיוצאים.',
+          monitor: 'מסך',
+          currentMonitor: 'המסך הנוכחי',
+          primary: 'ראשי',
+          idlePause: 'השהה כשאין פעילות',
+          idlePauseNever: 'אף פעם',
+          idlePauseMinutes: 'אחרי {{minutes}} דק׳'
+        },
         engineMode: {
           title: 'מצב',
           description: 'איפה המנוע ירוץ? כחלק מ-Biome או במקום אחר?',
@@ -173,7 +249,64 @@ const he = {
           connected: 'מחובר',
           unreachable: 'לא זמין',
           ownManaged: 'השרת המובנה של Biome',
-          placeholder: 'http://localhost:7987'
+          placeholder: 'http://localhost:7987',
+          profiles: 'שרתים שמורים',
+          saveProfile: 'שמור שרת זה',
+          removeProfile: 'הסר מהשרתים השמורים'
+        },
+        hfToken: {
+          title: 'טוקן HuggingFace',
+          description: 'נדרש למודלים מוגבלים; נשמר במחזיק המפתחות של המערכת',
+          token: 'טוקן גישה',
+          placeholder: 'hf_...',
+          checking: 'בודק...',
+          saved: 'נשמר · מחובר בתור {{username}}',
+          clear: 'נקה',
+          rejected: 'HuggingFace דחה את האסימון הזה.',
+          unverifiedOffline: 'נשמר · לא נבדק כי המצב הלא מקוון פעיל',
+          unverifiedHttp: 'נשמר · לא נבדק, HuggingFace החזיר HTTP {{status}}',
+          unverifiedNetwork: 'נשמר · לא נבדק, אין חיבור ל-HuggingFace',
+          saveFailed: 'לא ניתן לשמור את האסימון. בדוק את יומן האפליקציה לפרטים.'
+        },
+        issues: {
+          invalidJson: '{{file}} אינו קובץ JSON תקין.',
+          invalidValue: 'ערך לא תקין עבור {{path}}.',
+          invalidUrl: '"{{value}}" אינה כתובת URL תקינה.',
+          unsupportedProtocol: 'הפרוטוקול "{{protocol}}" אינו נתמך. השתמש ב-http, https, ws או wss.',
+          missingHost: 'לכתובת ה-URL אין מארח.',
+          emptyProfileName: 'שם הפרופיל ריק.',
+          directoryMissing: 'התיקייה {{path}} אינה קיימת.',
+          notADirectory: '{{path}} אינו תיקייה.'
+        },
+        offlineInstall: {
+          title: 'התקנה לא מקוונת',
+          description: 'מגדירים מחשב ללא אינטרנט?',
+          uvArchive: 'ארכיון uv',
+          uvArchiveHint: 'ארכיון השחרור של uv לפלטפורמה זו, שהורד במחשב מחובר.',
+          uvCache: 'מטמון חבילות',
+          uvCacheHint: 'תיקיית .uv/cache ממחשב שהשלים התקנה מקוונת. הפעל את המצב הלא מקוון ואז התקן את המנוע.',
+          choose: 'בחר...',
+          uvArchiveDialogTitle: 'בחר את ארכיון השחרור של uv',
+          uvCacheDialogTitle: 'בחר תיקיית מטמון של uv',
+          wrongArchive: 'נדרש ארכיון השחרור של uv לפלטפורמה זו ({{expected}}), התקבל {{got}}.',
+          notFound: 'הקובץ לא נמצא: {{path}}',
+          notACache: 'נראה ש-{{path}} אינו תיקיית מטמון של uv (חסר CACHEDIR.TAG).',
+          uvInstalled: 'uv {{version}} הותקן.',
+          cacheImported: 'מטמון החבילות יובא.'
+        },
+        engineLaunch: {
+          title: 'אפשרויות הפעלה',
+          description: 'איך תהליך המנוע המקומי מופעל',
+          gpuDevice: 'GPU',
+          gpuDeviceHint: 'מספר ההתקן עבור CUDA_VISIBLE_DEVICES. ריק משתמש ב-GPU ברירת המחדל.',
+          extraArgs: 'ארגומנטים נוספים',
+          extraArgsHint:
+            'מתווספים לשורת הפקודה של השרת, מופרדים ברווחים. אין תמיכה במירכאות, ולכן ארגומנט לא יכול להכיל רווח.',
+          extraEnv: 'משתני סביבה נוספים',
+          extraEnvHint: 'זוגות KEY=VALUE מופרדים ברווחים. אין תמיכה במירכאות, ולכן ערך לא יכול להכיל רווח.',
+          preview: 'פקודה',
+          autoRestart: 'הפעלה מחדש לאחר קריסה',
+          autoRestartDescription: 'מפעיל מחדש את המנוע אם הוא נסגר באופן בלתי צפוי, וממתין יותר אחרי כל קריסה.'
         },
         engine: {
           title: 'מנוע מקומי',
@@ -190,6 +323,10 @@ const he = {
           notInstalledTooltip: 'התקן את המנוע כדי לשנות את זה',
           startingTooltip: 'המתן עד שהמנוע יסיים לעלות',
           failedTooltip: 'תקן את המנוע כדי לשנות את זה',
+          crashed: 'המנוע נעצר באופן בלתי צפוי.',
+          crashedRepeatedly: 'המנוע המשיך לקרוס, ולכן Biome הפסיק להפעיל אותו מחדש.',
+          installRunning: 'התקנת מנוע כבר פועלת.',
+          installRunningElsewhere: 'תהליך Biome אחר מתקין או מעדכן את המנוע. המתן לסיומו ונסה שוב.',
           viewLogs: 'הצג לוגים'
         },
         performance: {
@@ -236,6 +373,13 @@ const he = {
           soundEffects: 'אפקטים קוליים',
           music: 'מוזיקה'
         },
+        accessibility: {
+          title: 'נגישות',
+          description: 'עזרה לפעולות שקשה להחזיק',
+          toggleMovement: 'תנועה במתג',
+          toggleMovementDescription:
+            'לחיצה אחת על מקש תנועה, ריצה או כריעה מתחילה את הפעולה ולחיצה נוספת עוצרת אותה, במקום להחזיק את המקש.'
+        },
         mouseSensitivity: {
           title: 'רגישות עכבר',
           description: 'כמה המצלמה צריכה לזוז כשאתה מזיז את העכבר?',
@@ -284,7 +428,8 @@ const he = {
             secondaryFire: 'ירי משני',
             pauseMenu: 'תפריט עצירה',
             resetScene: 'איפוס סצנה',
-            sceneEdit: 'עריכת סצנה'
+            sceneEdit: 'עריכת סצנה',
+            screenshot: 'צילום מסך'
           }
         },
         offlineMode: {
@@ -293,6 +438,20 @@ const he = {
           enabled: 'עבוד לא מקוון',
           enabledDescription: 'אפשר להמשיך להשתמש במה שכבר מותקן, אך התקנות מנוע מחדש והורדות מודלים ייכשלו.'
         },
+        network: {
+          title: 'רשת',
+          description: 'מאחורי פרוקסי, או צריך מראות להורדות חסומות?',
+          proxy: 'פרוקסי',
+          proxyHint: 'משמש להורדות של Biome עצמו ושל המנוע. ריק משתמש בהגדרות המערכת.',
+          uvMirror: 'מראה uv',
+          uvMirrorHint: 'מחליף את שרת GitHub Releases בהורדות uv.',
+          pypiMirror: 'אינדקס חבילות',
+          pypiMirrorHint: 'מחליף את PyPI כאינדקס החבילות ברירת המחדל.',
+          pythonMirror: 'מראה Python',
+          pythonMirrorHint: 'מחליף את שרת ההורדה של גרסאות Python מנוהלות.',
+          hfMirror: 'מראה HuggingFace',
+          hfMirrorHint: 'מחליף את נקודת הקצה של HuggingFace Hub עבור מודלים ובדיקות אסימון.'
+        },
         sceneAuthoring: {
           title: 'יצירת סצנות',
           description: 'רוצה ליצור ולערוך סצנות באמצעות פרומפטים של טקסט?',
@@ -301,7 +460,19 @@ const he = {
             'צור סצנות חדשות או ערוך את הסצנה הנוכחית בעזרת פרומפט טקסט, מופעל על ידי מודל תמונה מקומי. דורש 8–10GB נוספים של VRAM.',
           saveGenerated: 'שמור סצנות שנוצרו',
           saveGeneratedDescription:
-            'שמור כל סצנה שנוצרה ברשימת הסצנות שלך כדי שתוכל לחזור אליה או למחוק אותה מאוחר יותר.'
+            'שמור כל סצנה שנוצרה ברשימת הסצנות שלך כדי שתוכל לחזור אליה או למחוק אותה מאוחר יותר.',
+          promptHistory: 'היסטוריית הנחיות',
+          promptHistoryDescription: 'הנחיות שהשתמשת בהן מוצעות בזמן הקלדת הנחיה חדשה.',
+          clearPromptHistory: 'נקה',
+          promptHistoryCleared: 'נוקה'
+        },
+        screenshots: {
+          title: 'צילומי מסך',
+          description: 'רוצה לשמור רגע?',
+          copyToClipboard: 'העתק ללוח',
+          copyToClipboardDescription: 'שים כל צילום מסך גם בלוח, מוכן להדבקה.',
+          folder: 'תיקיית צילומי מסך',
+          folderDescription: 'לחץ על מקש צילום המסך בזמן משחק כדי לשמור כאן קובץ PNG.'
         },
         recording: {
           title: 'הקלטת וידאו',
@@ -328,6 +499,14 @@ const he = {
             'הקלט את כל הקלטים לקובץ בשרת לצורך ניגון חוזר. נשמר בתיקיית ה-temp של מערכת ההפעלה.',
           diagnostics: 'דיאגנוסטיקה',
           diagnosticsDescription: 'העתק מידע דיאגנוסטי ללוח לצורך דיווחי באגים.',
+          appLog: 'יומן האפליקציה',
+          appLogDescription: 'העתק את {{count}} השורות האחרונות של יומן האפליקציה, כולל הפעלות קודמות. נשמר ב-{{path}}',
+          crashReports: 'דוחות קריסה',
+          crashReportsDescription: '{{count}} מהפעלות קודמות. צרף את החדש ביותר לדיווח באג.',
+          crashReportsNone: 'לא נרשמו קריסות.',
+          crashKindReport: 'דוח',
+          crashKindMinidump: 'קובץ קריסה מקורי',
+          showCrashReport: 'הצג',
           copiedToClipboard: 'הועתק ללוח',
           copyFailed: 'ההעתקה נכשלה'
         },
@@ -357,6 +536,30 @@ const he = {
           placeholder: 'במה תרצה לשחק?'
         }
       },
+      screenshots: {
+        saved: 'צילום המסך נשמר',
+        savedAndCopied: 'צילום המסך נשמר והועתק'
+      },
+      tray: {
+        engineStopped: 'מנוע: מושבת',
+        engineStarting: 'מנוע: מופעל…',
+        engineReady: 'מנוע: מוכן',
+        engineError: 'מנוע: שגיאה',
+        tooltip: 'Biome — {{state}}',
+        show: 'הצג את Biome',
+        restartEngine: 'הפעל מחדש את המנוע',
+        openConfig: 'פתח הגדרות',
+        resetWindowPosition: 'אפס מיקום חלון',
+        quit: 'יציאה'
+      },
+      notifications: {
+        setupComplete: 'התקנת המנוע הושלמה',
+        setupFailed: 'התקנת המנוע נכשלה',
+        engineCrashed: 'המנוע קרס',
+        loadingFailed: 'טעינת העולם נכשלה',
+        exitCode: 'קוד יציאה {{code}}',
+        exitSignal: 'הופסק על ידי {{signal}}'
+      },
       scenes: {
         failedToReadImageData: 'קריאת נתוני התמונה נכשלה',
         noImageInClipboard: 'לא נמצאה תמונה בלוח'
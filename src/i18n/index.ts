@@ -5,6 +5,7 @@ import { resources } from './resources'
 import { FALLBACK_LOCALE, isSupportedLocale, type SupportedLocale } from './locales'
 import type { MessageId } from '../types/protocol.generated'
 import type { StageId } from '../stages'
+import type { SettingsIssueMessageId } from '../types/ipc'
 
 export { FALLBACK_LOCALE, LOCALE_DISPLAY_NAMES, SUPPORTED_LOCALES, type SupportedLocale } from './locales'
 
@@ -23,6 +24,9 @@ type _ServerMessageKey = Extract<TranslationKey, `app.server.error.${string}` |
 type _MessageIdsAreKeys = _Expect<MessageId extends TranslationKey ? true : false>
 type _MessageIdsCoverKeys = _Expect<_Equals<MessageId, _ServerMessageKey>>
 
+// `validate-settings` issues render via `t(message_id, params)`.
+type _SettingsIssuesAreKeys = _Expect<SettingsIssueMessageId extends TranslationKey ? true : false>
+
 // StageId ↔ `stage.*` — every stage we can render must have a translation,
 // and every stage translation must correspond to a stage we can render.
 type _StageTranslationKey = `stage.${StageId}`
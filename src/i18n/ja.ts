@@ -32,7 +32,10 @@ const ja = {
         copy: 'コピー',
         open: '開く',
         browseForImageFile: '画像ファイルを選択',
-        delete: '削除'
+        delete: '削除',
+        launch: '起動',
+        savedWorlds: '保存したワールド',
+        restartEngine: 'エンジンを再起動'
       },
       dialogs: {
         updateAvailable: {
@@ -44,6 +47,14 @@ const ja = {
           title: '接続が切断されました',
           description: 'エンジンとの接続が失われました。再接続しますか？'
         },
+        deepLinkConnect: {
+          title: 'サーバーに参加しますか？',
+          description: 'このリンクは Biome をサーバーモードに切り替え、{{url}} に接続しようとしています。共有元を信頼できる場合のみ続行してください。'
+        },
+        deepLinkWorld: {
+          title: 'ワールドを読み込みますか？',
+          description: 'このリンクはシード {{seed}} でセッションを開始しようとしています。'
+        },
         install: {
           title: 'インストール',
           installing: 'インストール中...',
@@ -57,6 +68,11 @@ const ja = {
           abortEngineInstall: 'エンジンのインストールを中止',
           closeInstallLogs: 'インストールログを閉じる'
         },
+        restartForHfToken: {
+          title: 'エンジンを再起動しますか？',
+          description:
+            '実行中のエンジンは以前の HuggingFace トークンを使用しています。新しいトークンを使うために今すぐ再起動しますか？'
+        },
         fixInPlace: {
           title: 'その場で修復しますか？',
           description: '削除は行わず、エンジン依存関係を再同期します。通常はアップデート後の問題解決にこれで十分です。'
@@ -85,6 +101,28 @@ const ja = {
           confirmDeleteDescription: '<bold>{{filename}}</bold> を削除しますか？この操作は取り消せません。',
           openExternally: '開く'
         },
+        savedWorlds: {
+          title: '保存したワールド',
+          description: '今いるワールドを保存して、あとで戻ってこられます。',
+          saveCurrent: '現在のワールドを保存',
+          empty: '保存したワールドはまだありません。今いるワールドを保存するとここに表示されます。',
+          load: '読み込む',
+          seedMissing: 'このワールドのシーン画像はもう存在しません。',
+          failed: '問題が発生しました。詳細はログを確認してください。',
+          share: '共有',
+          importFile: 'ワールドファイルを読み込む',
+          shared: '{{path}} に保存しました',
+          exportDialogTitle: 'ワールドファイルを保存',
+          importDialogTitle: 'ワールドファイルを開く',
+          worldFileType: 'Biome ワールドファイル',
+          notWorldFile: '{{file}} は Biome のワールドファイルではありません。',
+          versionMismatch: '{{file}} は Biome {{fileVersion}} で作成されました。現在の Biome は {{appVersion}} です。',
+          worldFileSeedMissing: '{{file}} にシーン画像が含まれていません。',
+          wrongExtension: 'ワールドファイルは .{{extension}} 形式で保存する必要があります。',
+          worldGone: 'この保存したワールドはもう存在しません。',
+          confirmDeleteTitle: '保存したワールドを削除しますか？',
+          confirmDeleteDescription: '<bold>{{name}}</bold> を削除しますか？この操作は取り消せません。'
+        },
         serverUnreachable: {
           title: 'サーバーに接続できません',
           withUrl:
@@ -165,6 +203,44 @@ const ja = {
           description: 'Biome で使用する言語はどれですか？',
           system: 'システム設定'
         },
+        updateChannel: {
+          title: 'アップデート',
+          description: 'どのリリースを通知しますか？',
+          stable: '安定版',
+          beta: 'ベータ版（プレリリース）'
+        },
+        settingsBackup: {
+          title: '設定のバックアップ',
+          description: '設定を別のマシンに移したり共有したりできます',
+          file: '設定ファイル',
+          export: 'エクスポート...',
+          import: 'インポート...',
+          importFailed: '設定をインポートできませんでした。詳細はアプリのログを確認してください。'
+        },
+        tray: {
+          title: 'システムトレイ',
+          description: 'Biome をバックグラウンドで実行し続けます',
+          enabled: 'トレイに最小化',
+          enabledDescription: 'ウィンドウを閉じると Biome はトレイに隠れ、エンジンは動作し続けます。終了はトレイメニューから行います。'
+        },
+        notifications: {
+          title: '通知',
+          description: '時間のかかる処理が終わったらお知らせします',
+          enabled: 'デスクトップ通知',
+          enabledDescription: 'Biome がバックグラウンドにあるときに、エンジンのセットアップ完了、ワールドの読み込み失敗、エンジンのクラッシュを通知します。'
+        },
+        display: {
+          title: '表示',
+          description: 'プレイ中のウィンドウの動作',
+          sessionFullscreen: 'プレイ中はフルスクリーン',
+          sessionFullscreenDescription: 'セッション中はフルスクリーンにし、画面がスリープしないようにします。終了すると元のウィンドウサイズに戻ります。',
+          monitor: 'モニター',
+          currentMonitor: '現在のモニター',
+          primary: 'メイン',
+          idlePause: '操作がないときに一時停止',
+          idlePauseNever: 'しない',
+          idlePauseMinutes: '{{minutes}} 分後'
+        },
         engineMode: {
           title: 'モード',
           description: 'エンジンをどこで動かしますか？ Biome 内ですか、それとも外部ですか？',
@@ -179,7 +255,69 @@ const ja = {
           connected: '接続済み',
           unreachable: '接続不可',
           ownManaged: 'Biome の組み込みサーバー',
-          placeholder: 'http://localhost:7987'
+          placeholder: 'http://localhost:7987',
+          profiles: '保存済みサーバー',
+          saveProfile: 'このサーバーを保存',
+          removeProfile: '保存済みサーバーから削除'
+        },
+        hfToken: {
+          title: 'HuggingFace トークン',
+          description: 'ゲート付きモデルに必要です。OS のキーチェーンに保存されます',
+          token: 'アクセストークン',
+          placeholder: 'hf_...',
+          checking: '確認中...',
+          saved: '保存済み · {{username}} としてサインイン中',
+          clear: '削除',
+          rejected: 'HuggingFace がこのトークンを拒否しました。',
+          unverifiedOffline: '保存済み · オフラインモードのため未確認',
+          unverifiedHttp: '保存済み · HuggingFace が HTTP {{status}} を返したため未確認',
+          unverifiedNetwork: '保存済み · HuggingFace に接続できないため未確認',
+          saveFailed: 'トークンを保存できませんでした。詳細はアプリのログを確認してください。'
+        },
+        issues: {
+          invalidJson: '{{file}} は有効な JSON ファイルではありません。',
+          invalidValue: '{{path}} の値が無効です。',
+          invalidUrl: '「{{value}}」は有効な URL ではありません。',
+          unsupportedProtocol:
+            'プロトコル「{{protocol}}」には対応していません。http、https、ws、wss のいずれかを使用してください。',
+          missingHost: 'URL にホストがありません。',
+          emptyProfileName: 'プロファイル名が空です。',
+          directoryMissing: 'フォルダ {{path}} が存在しません。',
+          notADirectory: '{{path}} はフォルダではありません。'
+        },
+        offlineInstall: {
+          title: 'オフラインインストール',
+          description: 'インターネットのないマシンをセットアップしますか？',
+          uvArchive: 'uv アーカイブ',
+          uvArchiveHint: '接続されたマシンでダウンロードした、このプラットフォーム用の uv リリースアーカイブ。',
+          uvCache: 'パッケージキャッシュ',
+          uvCacheHint:
+            'オンラインインストールを完了したマシンの .uv/cache フォルダ。オフラインモードをオンにしてからエンジンをインストールしてください。',
+          choose: '選択...',
+          uvArchiveDialogTitle: 'uv リリースアーカイブを選択',
+          uvCacheDialogTitle: 'uv キャッシュフォルダを選択',
+          wrongArchive:
+            'このプラットフォーム用の uv リリースアーカイブ ({{expected}}) が必要ですが、{{got}} が選択されました。',
+          notFound: 'ファイルが見つかりません: {{path}}',
+          notACache: '{{path}} は uv キャッシュフォルダではないようです (CACHEDIR.TAG がありません)。',
+          uvInstalled: 'uv {{version}} をインストールしました。',
+          cacheImported: 'パッケージキャッシュをインポートしました。'
+        },
+        engineLaunch: {
+          title: '起動オプション',
+          description: 'ローカルエンジンプロセスの起動方法',
+          gpuDevice: 'GPU',
+          gpuDeviceHint: 'CUDA_VISIBLE_DEVICES に渡すデバイス番号。空欄の場合は既定の GPU を使用します。',
+          extraArgs: '追加引数',
+          extraArgsHint:
+            'サーバーのコマンドラインに追加されます（スペース区切り）。引用符は使えないため、引数にスペースを含めることはできません。',
+          extraEnv: '追加環境変数',
+          extraEnvHint:
+            'KEY=VALUE をスペース区切りで指定します。引用符は使えないため、値にスペースを含めることはできません。',
+          preview: 'コマンド',
+          autoRestart: 'クラッシュ後に再起動',
+          autoRestartDescription:
+            'エンジンが予期せず終了した場合に再起動します。クラッシュのたびに待機時間が長くなります。'
         },
         engine: {
           title: 'ローカルエンジン',
@@ -197,6 +335,11 @@ const ja = {
           notInstalledTooltip: '変更するにはエンジンをインストールしてください',
           startingTooltip: 'エンジンの起動が完了するまでお待ちください',
           failedTooltip: '変更するにはエンジンを修復してください',
+          crashed: 'エンジンが予期せず停止しました。',
+          crashedRepeatedly: 'エンジンのクラッシュが続いたため、Biome は再起動を停止しました。',
+          installRunning: 'エンジンのインストールはすでに実行中です。',
+          installRunningElsewhere:
+            '別の Biome プロセスがエンジンをインストールまたは更新しています。完了してから再試行してください。',
           viewLogs: 'ログを表示'
         },
         performance: {
@@ -245,6 +388,12 @@ const ja = {
           soundEffects: '効果音',
           music: '音楽'
         },
+        accessibility: {
+          title: 'アクセシビリティ',
+          description: '押し続けにくい操作の補助',
+          toggleMovement: '移動をトグル',
+          toggleMovementDescription: '移動・ダッシュ・しゃがみのキーを押し続ける代わりに、1回押すと開始し、もう1回押すと停止します。'
+        },
         mouseSensitivity: {
           title: 'マウス感度',
           description: 'マウス移動に対してカメラをどれだけ動かしますか？',
@@ -293,7 +442,8 @@ const ja = {
             secondaryFire: 'サブ射撃',
             pauseMenu: 'ポーズメニュー',
             resetScene: 'シーンをリセット',
-            sceneEdit: 'シーン編集'
+            sceneEdit: 'シーン編集',
+            screenshot: 'スクリーンショット'
           }
         },
         offlineMode: {
@@ -303,6 +453,20 @@ const ja = {
           enabledDescription:
             'すでにセットアップ済みのものはそのまま使用できますが、エンジンの再インストールやモデルのダウンロードは失敗します。'
         },
+        network: {
+          title: 'ネットワーク',
+          description: 'プロキシの内側にいる、またはブロックされたダウンロードのためにミラーが必要ですか？',
+          proxy: 'プロキシ',
+          proxyHint: 'Biome 自身のダウンロードとエンジンで使用します。空欄の場合はシステム設定を使用します。',
+          uvMirror: 'uv ミラー',
+          uvMirrorHint: 'uv のダウンロード元である GitHub Releases を置き換えます。',
+          pypiMirror: 'パッケージインデックス',
+          pypiMirrorHint: 'デフォルトのパッケージインデックス (PyPI) を置き換えます。',
+          pythonMirror: 'Python ミラー',
+          pythonMirrorHint: 'マネージド Python ビルドのダウンロード元を置き換えます。',
+          hfMirror: 'HuggingFace ミラー',
+          hfMirrorHint: 'モデルとトークン確認に使う HuggingFace Hub のエンドポイントを置き換えます。'
+        },
         sceneAuthoring: {
           title: 'シーンオーサリング',
           description: 'テキストプロンプトでシーンを作成・編集しませんか？',
@@ -310,7 +474,19 @@ const ja = {
           enabledDescription:
             'ローカル画像モデルを利用して、テキストプロンプトから新しいシーンを生成したり、現在のシーンを編集したりできます。追加で8〜10GBのVRAMが必要です。',
           saveGenerated: '生成したシーンを保存',
-          saveGeneratedDescription: '生成したシーンをすべてシーン一覧に残し、後から再利用・削除できるようにします。'
+          saveGeneratedDescription: '生成したシーンをすべてシーン一覧に残し、後から再利用・削除できるようにします。',
+          promptHistory: 'プロンプト履歴',
+          promptHistoryDescription: '入力中に、以前使ったプロンプトが候補として表示されます。',
+          clearPromptHistory: '消去',
+          promptHistoryCleared: '消去しました'
+        },
+        screenshots: {
+          title: 'スクリーンショット',
+          description: 'この瞬間を残しますか？',
+          copyToClipboard: 'クリップボードにコピー',
+          copyToClipboardDescription: 'スクリーンショットをクリップボードにも入れて、すぐに貼り付けられるようにします。',
+          folder: 'スクリーンショットフォルダ',
+          folderDescription: 'プレイ中にスクリーンショットキーを押すと、ここに PNG が保存されます。'
         },
         recording: {
           title: '動画録画',
@@ -337,6 +513,14 @@ const ja = {
             'リプレイ用に全入力をサーバー上のファイルに記録します。OSの一時ディレクトリに書き込まれます。',
           diagnostics: '診断情報',
           diagnosticsDescription: 'バグ報告用の診断情報をクリップボードにコピーします。',
+          appLog: 'アプリログ',
+          appLogDescription: '以前の起動分も含め、アプリログの最後の {{count}} 行をコピーします。保存先: {{path}}',
+          crashReports: 'クラッシュレポート',
+          crashReportsDescription: '以前の起動で {{count}} 件。最新のものをバグ報告に添付してください。',
+          crashReportsNone: 'クラッシュは記録されていません。',
+          crashKindReport: 'レポート',
+          crashKindMinidump: 'ネイティブクラッシュダンプ',
+          showCrashReport: '表示',
           copiedToClipboard: 'クリップボードにコピーしました',
           copyFailed: 'コピーに失敗しました'
         },
@@ -366,6 +550,30 @@ const ja = {
           placeholder: '何をプレイしたいですか？'
         }
       },
+      screenshots: {
+        saved: 'スクリーンショットを保存しました',
+        savedAndCopied: 'スクリーンショットを保存してコピーしました'
+      },
+      tray: {
+        engineStopped: 'エンジン: 停止中',
+        engineStarting: 'エンジン: 起動中…',
+        engineReady: 'エンジン: 準備完了',
+        engineError: 'エンジン: エラー',
+        tooltip: 'Biome — {{state}}',
+        show: 'Biome を表示',
+        restartEngine: 'エンジンを再起動',
+        openConfig: '設定ファイルを開く',
+        resetWindowPosition: 'ウィンドウ位置をリセット',
+        quit: '終了'
+      },
+      notifications: {
+        setupComplete: 'エンジンのセットアップが完了しました',
+        setupFailed: 'エンジンのセットアップに失敗しました',
+        engineCrashed: 'エンジンがクラッシュしました',
+        loadingFailed: 'ワールドの読み込みに失敗しました',
+        exitCode: '終了コード {{code}}',
+        exitSignal: 'シグナル {{signal}} で終了'
+      },
       scenes: {
         failedToReadImageData: '画像データの読み取りに失敗しました',
         noImageInClipboard: 'クリップボードに画像が見つかりません'
@@ -32,7 +32,10 @@ const zh = {
         copy: '复制',
         open: '打开',
         browseForImageFile: '浏览图片文件',
-        delete: '删除'
+        delete: '删除',
+        launch: '启动',
+        savedWorlds: '已保存的世界',
+        restartEngine: '重启引擎'
       },
       dialogs: {
         updateAvailable: {
@@ -43,6 +46,14 @@ const zh = {
           title: '连接已断开',
           description: '与引擎的连接已丢失。要尝试重新连接吗？'
         },
+        deepLinkConnect: {
+          title: '加入服务器？',
+          description: '此链接将把 Biome 切换到服务器模式并连接到 {{url}}。请仅在信任分享者时继续。'
+        },
+        deepLinkWorld: {
+          title: '加载世界？',
+          description: '此链接将使用种子 {{seed}} 开始会话。'
+        },
         install: {
           title: '安装',
           installing: '安装中...',
@@ -56,6 +67,10 @@ const zh = {
           abortEngineInstall: '中止引擎安装',
           closeInstallLogs: '关闭安装日志'
         },
+        restartForHfToken: {
+          title: '要重启引擎吗？',
+          description: '正在运行的引擎仍在使用之前的 HuggingFace 令牌。现在重启以使用新令牌吗？'
+        },
         fixInPlace: {
           title: '原地修复？',
           description: '这会重新同步引擎依赖项，但不会删除任何内容。通常足以修复更新后的问题。'
@@ -82,6 +97,28 @@ const zh = {
           confirmDeleteDescription: '删除 <bold>{{filename}}</bold>？此操作无法撤销。',
           openExternally: '打开'
         },
+        savedWorlds: {
+          title: '已保存的世界',
+          description: '保存当前所在的世界，以便稍后回来。',
+          saveCurrent: '保存当前世界',
+          empty: '还没有已保存的世界。保存当前所在的世界后会显示在这里。',
+          load: '载入',
+          seedMissing: '此世界的场景图片已不存在。',
+          failed: '出现问题。请查看日志了解详情。',
+          share: '分享',
+          importFile: '导入世界文件',
+          shared: '已保存到 {{path}}',
+          exportDialogTitle: '保存世界文件',
+          importDialogTitle: '打开世界文件',
+          worldFileType: 'Biome 世界文件',
+          notWorldFile: '{{file}} 不是 Biome 世界文件。',
+          versionMismatch: '{{file}} 由 Biome {{fileVersion}} 创建；当前为 Biome {{appVersion}}。',
+          worldFileSeedMissing: '{{file}} 缺少场景图片。',
+          wrongExtension: '世界文件必须保存为 .{{extension}} 文件。',
+          worldGone: '此已保存的世界已不存在。',
+          confirmDeleteTitle: '删除已保存的世界？',
+          confirmDeleteDescription: '删除 <bold>{{name}}</bold>？此操作无法撤销。'
+        },
         serverUnreachable: {
           title: '无法连接到服务器',
           withUrl: '无法连接到 {{url}}。服务器可能已停止、URL 有误，或被防火墙拦截。',
@@ -157,6 +194,44 @@ const zh = {
           description: 'Biome 应该使用哪种语言？',
           system: '跟随系统'
         },
+        updateChannel: {
+          title: '更新',
+          description: 'Biome 应该提示哪些版本？',
+          stable: '稳定版',
+          beta: '测试版（预发布）'
+        },
+        settingsBackup: {
+          title: '设置备份',
+          description: '将你的设置迁移到另一台机器或与他人共享',
+          file: '设置文件',
+          export: '导出...',
+          import: '导入...',
+          importFailed: '无法导入设置。请查看应用日志了解详情。'
+        },
+        tray: {
+          title: '系统托盘',
+          description: '让 Biome 在后台保持运行',
+          enabled: '最小化到托盘',
+          enabledDescription: '关闭窗口时 Biome 会隐藏到托盘，引擎继续运行。请从托盘菜单退出。'
+        },
+        notifications: {
+          title: '通知',
+          description: '耗时操作完成时提醒你',
+          enabled: '桌面通知',
+          enabledDescription: '当 Biome 在后台时，在引擎安装完成、世界加载失败或引擎崩溃时发出通知。'
+        },
+        display: {
+          title: '显示',
+          description: '游玩时窗口的行为',
+          sessionFullscreen: '游玩时全屏',
+          sessionFullscreenDescription: '每次会话期间全屏显示并保持屏幕常亮。离开后窗口恢复到之前的大小。',
+          monitor: '显示器',
+          currentMonitor: '当前显示器',
+          primary: '主显示器',
+          idlePause: '空闲时暂停',
+          idlePauseNever: '从不',
+          idlePauseMinutes: '{{minutes}} 分钟后'
+        },
         engineMode: {
           title: '模式',
           description: '引擎在哪里运行？在 Biome 内，还是在别处？',
@@ -171,7 +246,63 @@ const zh = {
           connected: '已连接',
           unreachable: '无法访问',
           ownManaged: 'Biome 的内置服务器',
-          placeholder: 'http://localhost:7987'
+          placeholder: 'http://localhost:7987',
+          profiles: '已保存的服务器',
+          saveProfile: '保存此服务器',
+          removeProfile: '从已保存的服务器中移除'
+        },
+        hfToken: {
+          title: 'HuggingFace 令牌',
+          description: '访问受限模型时需要；保存在系统钥匙串中',
+          token: '访问令牌',
+          placeholder: 'hf_...',
+          checking: '检查中...',
+          saved: '已保存 · 登录为 {{username}}',
+          clear: '清除',
+          rejected: 'HuggingFace 拒绝了此令牌。',
+          unverifiedOffline: '已保存 · 离线模式已开启，未验证',
+          unverifiedHttp: '已保存 · HuggingFace 返回 HTTP {{status}}，未验证',
+          unverifiedNetwork: '已保存 · 无法连接 HuggingFace，未验证',
+          saveFailed: '无法保存令牌。请查看应用日志了解详情。'
+        },
+        issues: {
+          invalidJson: '{{file}} 不是有效的 JSON 文件。',
+          invalidValue: '{{path}} 的值无效。',
+          invalidUrl: '“{{value}}”不是有效的 URL。',
+          unsupportedProtocol: '不支持的协议“{{protocol}}”。请使用 http、https、ws 或 wss。',
+          missingHost: 'URL 缺少主机。',
+          emptyProfileName: '配置名称为空。',
+          directoryMissing: '文件夹 {{path}} 不存在。',
+          notADirectory: '{{path}} 不是文件夹。'
+        },
+        offlineInstall: {
+          title: '离线安装',
+          description: '要在没有网络的机器上安装吗？',
+          uvArchive: 'uv 压缩包',
+          uvArchiveHint: '在联网机器上下载的、适用于此平台的 uv 发行压缩包。',
+          uvCache: '软件包缓存',
+          uvCacheHint: '已完成在线安装的机器上的 .uv/cache 文件夹。开启离线模式后再安装引擎。',
+          choose: '选择...',
+          uvArchiveDialogTitle: '选择 uv 发行压缩包',
+          uvCacheDialogTitle: '选择 uv 缓存文件夹',
+          wrongArchive: '需要此平台的 uv 发行压缩包（{{expected}}），但选择的是 {{got}}。',
+          notFound: '找不到文件：{{path}}',
+          notACache: '{{path}} 看起来不是 uv 缓存文件夹（缺少 CACHEDIR.TAG）。',
+          uvInstalled: '已安装 uv {{version}}。',
+          cacheImported: '已导入软件包缓存。'
+        },
+        engineLaunch: {
+          title: '启动选项',
+          description: '本地引擎进程的启动方式',
+          gpuDevice: 'GPU',
+          gpuDeviceHint: '用于 CUDA_VISIBLE_DEVICES 的设备编号。留空则使用默认 GPU。',
+          extraArgs: '额外参数',
+          extraArgsHint: '追加到服务器命令行，以空格分隔。不支持引号，因此参数中不能包含空格。',
+          extraEnv: '额外环境变量',
+          extraEnvHint: '以空格分隔的 KEY=VALUE 对。不支持引号，因此值中不能包含空格。',
+          preview: '命令',
+          autoRestart: '崩溃后重启',
+          autoRestartDescription: '引擎意外退出时自动重启，每次崩溃后等待时间逐渐延长。'
         },
         engine: {
           title: '本地引擎',
@@ -188,6 +319,10 @@ const zh = {
           notInstalledTooltip: '安装引擎以更改此项',
           startingTooltip: '等待引擎启动完成',
           failedTooltip: '修复引擎以更改此项',
+          crashed: '引擎意外停止。',
+          crashedRepeatedly: '引擎反复崩溃，Biome 已停止重启。',
+          installRunning: '引擎安装已在运行。',
+          installRunningElsewhere: '另一个 Biome 进程正在安装或更新引擎。请等待其完成后重试。',
           viewLogs: '查看日志'
         },
         performance: {
@@ -233,6 +368,12 @@ const zh = {
           soundEffects: '音效',
           music: '音乐'
         },
+        accessibility: {
+          title: '辅助功能',
+          description: '为难以按住的操作提供帮助',
+          toggleMovement: '切换式移动',
+          toggleMovementDescription: '按一次移动、冲刺或蹲下键开始，再按一次停止，无需一直按住。'
+        },
         mouseSensitivity: {
           title: '鼠标灵敏度',
           description: '移动鼠标时，镜头应该移动多少？',
@@ -281,7 +422,8 @@ const zh = {
             secondaryFire: '副射击',
             pauseMenu: '暂停菜单',
             resetScene: '重置场景',
-            sceneEdit: '场景编辑'
+            sceneEdit: '场景编辑',
+            screenshot: '截图'
           }
         },
         offlineMode: {
@@ -290,13 +432,39 @@ const zh = {
           enabled: '离线工作',
           enabledDescription: '你可以继续使用已经配置好的内容，但引擎重新安装和模型下载将会失败。'
         },
+        network: {
+          title: '网络',
+          description: '在代理后面，或者需要镜像来下载被屏蔽的内容？',
+          proxy: '代理',
+          proxyHint: '用于 Biome 自身的下载和引擎。留空则使用系统设置。',
+          uvMirror: 'uv 镜像',
+          uvMirrorHint: '替换 uv 下载所用的 GitHub Releases 地址。',
+          pypiMirror: '软件包索引',
+          pypiMirrorHint: '替换默认的软件包索引 PyPI。',
+          pythonMirror: 'Python 镜像',
+          pythonMirrorHint: '替换托管 Python 构建的下载地址。',
+          hfMirror: 'HuggingFace 镜像',
+          hfMirrorHint: '替换用于模型和令牌检查的 HuggingFace Hub 端点。'
+        },
         sceneAuthoring: {
           title: '场景创作',
           description: '想通过文字提示创建和修改场景吗？',
           enabled: '启用场景创作',
           enabledDescription: '使用本地图像模型通过文字提示生成新场景或编辑当前场景。需要额外 8-10GB 显存。',
           saveGenerated: '保存生成的场景',
-          saveGeneratedDescription: '将生成的每个场景保留在场景列表中，以便日后重新使用或删除。'
+          saveGeneratedDescription: '将生成的每个场景保留在场景列表中，以便日后重新使用或删除。',
+          promptHistory: '提示词历史',
+          promptHistoryDescription: '输入时会推荐你用过的提示词。',
+          clearPromptHistory: '清除',
+          promptHistoryCleared: '已清除'
+        },
+        screenshots: {
+          title: '截图',
+          description: '想留住这一刻吗？',
+          copyToClipboard: '复制到剪贴板',
+          copyToClipboardDescription: '同时把每张截图放到剪贴板，方便直接粘贴。',
+          folder: '截图文件夹',
+          folderDescription: '游戏时按下截图键，PNG 会保存到这里。'
         },
         recording: {
           title: '视频录制',
@@ -322,6 +490,14 @@ const zh = {
           actionLoggingDescription: '将所有输入记录到服务器上的文件以供回放。写入操作系统的临时目录。',
           diagnostics: '诊断信息',
           diagnosticsDescription: '将诊断信息复制到剪贴板，用于错误报告。',
+          appLog: '应用日志',
+          appLogDescription: '复制应用日志的最后 {{count}} 行，包括之前的运行。保存在 {{path}}',
+          crashReports: '崩溃报告',
+          crashReportsDescription: '之前的运行中有 {{count}} 份。请把最新的附到错误报告中。',
+          crashReportsNone: '没有崩溃记录。',
+          crashKindReport: '报告',
+          crashKindMinidump: '原生崩溃转储',
+          showCrashReport: '显示',
           copiedToClipboard: '已复制到剪贴板',
           copyFailed: '复制失败'
         },
@@ -351,6 +527,30 @@ const zh = {
           placeholder: '你想玩什么？'
         }
       },
+      screenshots: {
+        saved: '截图已保存',
+        savedAndCopied: '截图已保存并复制'
+      },
+      tray: {
+        engineStopped: '引擎：已停止',
+        engineStarting: '引擎：启动中…',
+        engineReady: '引擎：就绪',
+        engineError: '引擎：错误',
+        tooltip: 'Biome — {{state}}',
+        show: '显示 Biome',
+        restartEngine: '重启引擎',
+        openConfig: '打开配置',
+        resetWindowPosition: '重置窗口位置',
+        quit: '退出'
+      },
+      notifications: {
+        setupComplete: '引擎设置完成',
+        setupFailed: '引擎设置失败',
+        engineCrashed: '引擎崩溃',
+        loadingFailed: '世界加载失败',
+        exitCode: '退出代码 {{code}}',
+        exitSignal: '被信号 {{signal}} 终止'
+      },
       scenes: {
         failedToReadImageData: '无法读取图片数据',
         noImageInClipboard: '剪贴板中未找到图片'
@@ -0,0 +1,20 @@
+import type { LogRecord } from '../types/ipc'
+
+/** Render a single `LogRecord` as a flat string for places the renderer
+ *  can't markup — clipboard exports, GitHub-issue body, etc.  Layout
+ *  matches the Python / Electron text renderers: `timestamp [level]
+ *  [logger] event k=v ...`, with the logger pill before the event so the
+ *  fixed prefix block stays scannable. */
+export function formatLogRecordPlainText(record: LogRecord): string {
+  const parts: string[] = []
+  if (record.timestamp) parts.push(record.timestamp)
+  if (record.level) parts.push(`[${record.level}]`)
+  if (record.logger) parts.push(`[${record.logger}]`)
+  parts.push(record.event)
+  if (record.fields) {
+    for (const [k, v] of Object.entries(record.fields)) parts.push(`${k}=${String(v)}`)
+  }
+  let line = parts.join(' ')
+  if (record.exception) line += `\n${record.exception}`
+  return line
+}
@@ -19,6 +19,11 @@ export type SeedRecord = {
 
 export type EngineStatus = {
   uv_installed: boolean
+  /** Version our uv binary reports; null when it's missing or broken. */
+  uv_version: string | null
+  /** Installed uv is older than the app supports. Still usable — the
+   *  next install / fix run upgrades it. */
+  uv_outdated: boolean
   repo_cloned: boolean
   dependencies_synced: boolean
   server_running: boolean
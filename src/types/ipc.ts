@@ -2,6 +2,7 @@ import type { EngineStatus, SeedFileRecord, SeedSource } from './app'
 import type { Settings } from './settings'
 import type { EngineBackend, ServerCapabilities } from './protocol.generated'
 import type { PortalSparksTuning } from '../lib/portalSparksTuning'
+import type { SessionDraft, SessionRecord } from './sessions'
 
 // `ServerCapabilities` is the Pydantic model in `server.protocol`,
 // shipped through codegen. Re-exported here so consumers reach for it
@@ -291,6 +292,16 @@ export type ExportDiagnosticsResult = {
   file_path: string | null
 }
 
+/** A crash artefact left behind by a previous run. `report` is the JSON
+ *  written by the main process (uncaught exception, renderer/child process
+ *  gone); `minidump` is a native dump from Chromium's crash reporter. */
+export type CrashReportEntry = {
+  path: string
+  kind: 'report' | 'minidump'
+  size_bytes: number
+  created_at_ms: number
+}
+
 export type AppUpdateInfo = {
   current_version: string
   latest_version: string
@@ -309,6 +320,179 @@ export type RecordingEntry = {
   properties: RecordingProperties | null
 }
 
+export type ScreenshotResult = {
+  /** Absolute path of the written PNG. */
+  path: string
+  copied_to_clipboard: boolean
+}
+
+/** Translation keys a `SettingsIssue` can carry. Checked against the
+ *  locale files by the drift gate in `src/i18n/index.ts`. */
+export type SettingsIssueMessageId =
+  | 'app.settings.issues.invalidJson'
+  | 'app.settings.issues.invalidValue'
+  | 'app.settings.issues.invalidUrl'
+  | 'app.settings.issues.unsupportedProtocol'
+  | 'app.settings.issues.missingHost'
+  | 'app.settings.issues.emptyProfileName'
+  | 'app.settings.issues.directoryMissing'
+  | 'app.settings.issues.notADirectory'
+
+/** Outcome of an air-gapped setup step (`install-uv-from-archive`,
+ *  `import-uv-cache`). `message` is already translated. */
+export type OfflineInstallResult = {
+  ok: boolean
+  message: string
+}
+
+/** One problem found by `validate-settings`. `path` is the dot-path of
+ *  the offending field (`server_profiles.1.url`) so the settings UI can
+ *  attach the message inline. The UI renders `message_id` + `params`;
+ *  `message` is the untranslated detail for logs. */
+export type SettingsIssue = {
+  path: string
+  message_id: SettingsIssueMessageId
+  params?: Record<string, string>
+  message: string
+}
+
+export type ExportSettingsResult = {
+  canceled: boolean
+  file_path: string | null
+}
+
+/** Outcome of `import-settings`. An unusable file is reported as issues
+ *  rather than thrown, so the settings UI can render them translated. */
+export type ImportSettingsResult = { ok: true; settings: Settings } | { ok: false; issues: SettingsIssue[] }
+
+/** Outcome of `export-world-file`. `message` is already translated. */
+export type ExportWorldFileResult = { ok: true; file_path: string } | { ok: false; message: string }
+
+/** Outcome of `import-world-file`. `message` is already translated. */
+export type ImportWorldFileResult = { ok: true; session: SessionRecord } | { ok: false; message: string }
+
+/** Outcome of checking a HuggingFace token against the hub's whoami
+ *  endpoint. `username` is the account the token belongs to. */
+export type HfTokenCheckResult = {
+  /** `unverified` means the hub couldn't be asked (offline mode, network
+   *  error, hub outage). Such a token is still stored; only a `rejected`
+   *  one is refused. */
+  status: 'valid' | 'rejected' | 'unverified'
+  username: string | null
+  /** Translated reason for `rejected` / `unverified`. */
+  message: string | null
+}
+
+/** Result of `verify-engine-env`: every module in `checked` was imported
+ *  inside the engine venv; `broken` lists the ones that failed and why. */
+export type EngineEnvReport = {
+  ok: boolean
+  checked: string[]
+  broken: { module: string; error: string }[]
+  /** Optional modules that failed to import; these don't affect `ok`. */
+  warnings: { module: string; error: string }[]
+}
+
+/** Action requested by a `biome://` link, already validated by main.
+ *  The renderer confirms with the user before applying it. */
+export type DeepLink = { kind: 'connect'; server_url: string } | { kind: 'world'; seed: string }
+
+/** One action `reinstall-engine` would take. `download_bytes` and
+ *  `locked_packages` are null when they couldn't be determined (offline,
+ *  unreadable lock file). */
+export type EngineSetupStep =
+  | {
+      kind: 'install-uv'
+      version: string
+      installed_version: string | null
+      download_url: string
+      download_bytes: number | null
+    }
+  | { kind: 'copy-server-files'; engine_dir: string }
+  | { kind: 'sync-packages'; fresh: boolean; locked_packages: number | null }
+
+/** Result of `plan-engine-setup`: the steps a setup run would take and
+ *  whether the disk space estimate fits on every volume it writes to. */
+export type EngineSetupPlan = {
+  steps: EngineSetupStep[]
+  required_bytes: number
+  volumes: { path: string; available_bytes: number }[]
+  fits: boolean
+}
+
+/** A package pinned in the engine's `uv.lock`. `source_kind` is the key
+ *  of its `source` table (`registry`, `git`, `url`, `editable`, ...) and
+ *  `source` that key's value. */
+export type LockedPackage = {
+  name: string
+  version: string
+  source_kind: string
+  source: string
+}
+
+/** A package locked at different versions in two locks. Names locked at
+ *  several versions (per-platform variants) list them comma-separated. */
+export type LockedPackageChange = { name: string; from: string; to: string }
+
+/** Result of `audit-engine-dependencies`. `wheel_bytes` sums the wheels
+ *  this platform downloads; packages whose index omits wheel sizes
+ *  (`unsized_wheels`) or that build from source (`source_builds`) are
+ *  listed rather than counted. The diff is against the lock of the last
+ *  successful sync and is empty when `has_previous_lock` is false. */
+export type EngineDependencyAudit = {
+  packages: number
+  wheel_bytes: number
+  unsized_wheels: string[]
+  source_builds: string[]
+  non_pypi: LockedPackage[]
+  has_previous_lock: boolean
+  added: LockedPackage[]
+  removed: LockedPackage[]
+  changed: LockedPackageChange[]
+}
+
+/** Engine state shown in the tray icon's tooltip and menu, mapped from
+ *  the renderer's lifecycle state. */
+export type TrayEngineState = 'stopped' | 'starting' | 'ready' | 'error'
+
+/** Long operations that post a desktop notification when they finish
+ *  while Biome is in the background. */
+export type AppNotificationKind = 'setup-complete' | 'setup-failed' | 'engine-crashed' | 'loading-failed'
+
+/** The standalone server command line `start-engine-server` would run.
+ *  `env` lists only what Biome sets on top of its own environment. */
+export type LaunchPreview = {
+  command: string
+  args: string[]
+  cwd: string
+  env: Record<string, string>
+}
+
+/** Housekeeping jobs run by the maintenance schedule. */
+export type MaintenanceJob = 'uv-cache' | 'crash-reports' | 'stale-downloads' | 'update-check'
+
+/** Outcome of one job in a maintenance pass. */
+export type MaintenanceResult = {
+  job: MaintenanceJob
+  status: 'ran' | 'skipped' | 'disabled' | 'failed'
+  detail: string
+}
+
+/** A connected display, as offered by the session-mode monitor picker. */
+export type MonitorInfo = {
+  id: number
+  label: string
+  width: number
+  height: number
+  primary: boolean
+}
+
+/** One line of the prompt-history log (`prompt_history.jsonl`). */
+export type PromptHistoryEntry = {
+  prompt: string
+  used_at_ms: number
+}
+
 /**
  * Maps each IPC command channel to its argument tuple and return type.
  * This is the single source of truth for all invoke() calls.
@@ -318,7 +502,14 @@ export type IpcCommandMap = {
   'read-settings': { args: []; return: Settings }
   'read-default-settings': { args: []; return: Settings }
   'write-settings': { args: [settings: Settings]; return: void }
+  'validate-settings': { args: [settings: unknown]; return: SettingsIssue[] }
+  /** Top-level settings keys currently forced by `--server` / `BIOME_*`
+   *  env vars. `read-settings` already reflects them; this is for the UI
+   *  to flag the field as overridden. */
+  'get-settings-overrides': { args: []; return: (keyof Settings)[] }
   'get-settings-path-str': { args: []; return: string }
+  'export-settings': { args: []; return: ExportSettingsResult }
+  'import-settings': { args: []; return: ImportSettingsResult | null }
   'open-settings': { args: []; return: void }
 
   // Models — thin proxies to the engine server. `list-models`
@@ -339,8 +530,25 @@ export type IpcCommandMap = {
   'check-engine-status': { args: [source?: string]; return: EngineStatus }
   'abort-engine-install': { args: []; return: string }
   'unpack-server-files': { args: [force: boolean]; return: string }
+  // Dry run of `reinstall-engine`: reports what it would do, changes nothing.
+  'plan-engine-setup': { args: []; return: EngineSetupPlan }
+  // Packages, sizes and sources in the engine's uv.lock, diffed against
+  // the last installed lock. Read-only.
+  'audit-engine-dependencies': { args: []; return: EngineDependencyAudit }
   'reinstall-engine': { args: []; return: string }
   'nuke-and-reinstall-engine': { args: []; return: string }
+  'get-uv-version': { args: []; return: { installed: string | null; supported: string } }
+  'upgrade-uv': { args: [targetVersion?: string]; return: string }
+  'verify-engine-env': { args: []; return: EngineEnvReport }
+  'repair-engine-env': { args: []; return: string }
+  'install-uv-from-archive': { args: [archivePath?: string]; return: OfflineInstallResult | null }
+  'import-uv-cache': { args: [cacheDir?: string]; return: OfflineInstallResult | null }
+
+  // HuggingFace token for gated models. Stored encrypted via the OS
+  // keychain and passed to the standalone server as `HF_TOKEN`.
+  'set-hf-token': { args: [token: string]; return: HfTokenCheckResult }
+  'clear-hf-token': { args: []; return: void }
+  'check-stored-hf-token': { args: []; return: HfTokenCheckResult | null }
 
   // Server
   'start-engine-server': { args: [port: number]; return: string }
@@ -350,6 +558,7 @@ export type IpcCommandMap = {
   'is-port-in-use': { args: [port: number]; return: boolean }
   'probe-server-health': { args: [healthUrl: string, timeoutMs?: number]; return: ServerHealthResult }
   'get-last-server-exit-tail': { args: []; return: string | null }
+  'get-launch-preview': { args: []; return: LaunchPreview }
 
   // Seeds
   'list-seeds': { args: []; return: SeedFileRecord[] }
@@ -373,18 +582,35 @@ export type IpcCommandMap = {
   'window-minimize': { args: []; return: void }
   'window-toggle-maximize': { args: []; return: void }
   'window-close': { args: []; return: void }
+  // Forget the persisted window geometry and re-center at the default size
+  // (for a window restored somewhere unreachable).
+  'reset-window-state': { args: []; return: void }
   'quit-app': { args: []; return: void }
+  // Session mode — fullscreen on a display (current one when omitted) with
+  // display sleep inhibited; exit restores the previous bounds.
+  'enter-session-mode': { args: [displayId?: number]; return: void }
+  'exit-session-mode': { args: []; return: void }
+  'list-monitors': { args: []; return: MonitorInfo[] }
+  // Moves an active session to another display; false when none is active.
+  'move-session-to-monitor': { args: [displayId: number]; return: boolean }
 
   // Debug
   'write-spark-tuning': { args: [tuning: PortalSparksTuning]; return: void }
   'get-runtime-diagnostics-meta': { args: []; return: RuntimeDiagnosticsMeta }
   'get-system-diagnostics': { args: []; return: SystemDiagnostics }
   'get-electron-log-tail': { args: []; return: LogRecord[] }
+  'get-app-log-path': { args: []; return: string }
+  'read-recent-logs': { args: [lines: number]; return: LogRecord[] }
+  'list-crash-reports': { args: []; return: CrashReportEntry[] }
+  'reveal-crash-report': { args: [reportPath: string]; return: void }
   'export-loading-diagnostics': { args: [reportText: string]; return: ExportDiagnosticsResult }
 
   // Updates
   'check-for-app-update': { args: []; return: AppUpdateInfo }
 
+  // Maintenance — runs every job now instead of waiting for the schedule.
+  'run-maintenance-now': { args: []; return: MaintenanceResult[] }
+
   // Recordings
   'get-default-video-dir': { args: []; return: string }
   'resolve-video-dir': { args: [configured: string]; return: string }
@@ -393,6 +619,42 @@ export type IpcCommandMap = {
   'delete-recording': { args: [filePath: string]; return: void }
   'open-recording-externally': { args: [filePath: string]; return: void }
   'open-recordings-folder': { args: [configured: string]; return: void }
+
+  // Sessions — saved world runs, persisted in `sessions.json`. `save-session`
+  // with an existing `id` updates that record in place; the optional JPEG
+  // thumbnail is stored next to the store, keyed by session id.
+  'save-session': { args: [draft: SessionDraft, thumbnailBase64?: string]; return: SessionRecord }
+  'list-sessions': { args: []; return: SessionRecord[] }
+  'load-session': { args: [id: string]; return: SessionRecord | null }
+  'delete-session': { args: [id: string]; return: void }
+  'get-session-thumbnail-base64': { args: [id: string]; return: string | null }
+  // World files — a saved session plus its seed image in one shareable
+  // `.biomeworld` file. Without a path, both ask with a file dialog and
+  // return null when it's cancelled. Import saves the session, adds the
+  // seed to uploads and queues the world like a `biome://world` link.
+  'export-world-file': { args: [sessionId: string, destPath?: string]; return: ExportWorldFileResult | null }
+  'import-world-file': { args: [srcPath?: string]; return: ImportWorldFileResult | null }
+
+  // Prompt history — Scene Authoring prompts, most recent first. An empty
+  // query lists recent prompts; otherwise results are fuzzy-ranked.
+  'add-prompt': { args: [prompt: string]; return: void }
+  'search-prompts': { args: [query: string, limit?: number]; return: PromptHistoryEntry[] }
+  'clear-prompt-history': { args: []; return: void }
+
+  // Screenshots — timestamped PNGs of the window contents in `screenshots/`
+  // under the user config dir.
+  'capture-screenshot': { args: [copyToClipboard: boolean]; return: ScreenshotResult }
+  'open-screenshots-folder': { args: []; return: void }
+
+  // Deep links — the `biome://` link that launched or re-focused the app,
+  // if one hasn't been collected yet.
+  'take-pending-deep-link': { args: []; return: DeepLink | null }
+
+  // Tray — the renderer reports lifecycle changes so the tray can show them.
+  'set-tray-engine-state': { args: [state: TrayEngineState]; return: void }
+
+  // Notifications — gated by `notifications.enabled` and window focus in main.
+  'show-notification': { args: [kind: AppNotificationKind, detail?: string]; return: void }
 }
 
 /**
@@ -402,5 +664,11 @@ export type IpcEventMap = {
   'server-ready': boolean
   'server-stage': { id: string; label: string; percent: number }
   'engine-log': LogRecord
+  'engine-crashed': { exit_code: number | null; signal: string | null; log_tail: string }
+  'settings-changed': Settings
+  'deep-link': DeepLink
+  'tray-restart-engine': void
+  'app-update-available': AppUpdateInfo
   'window-resized': { width: number; height: number }
+  'screenshot-saved': ScreenshotResult
 }
@@ -0,0 +1,52 @@
+import { z } from 'zod'
+import { EngineBackendSchema } from './protocol.generated'
+
+/** A saved world run — enough to put the user back into a world they
+ *  liked: which scene it started from, what the engine was asked to run,
+ *  and where. Persisted by the Electron main process in `sessions.json`
+ *  under the user config dir (`electron/ipc/sessions.ts`). */
+export const sessionRecordSchema = z.object({
+  id: z.string(),
+  // Seed filename + source address the image the same way `delete-seed`
+  // does, since filenames can collide across uploads / generated.
+  seed_filename: z.string(),
+  seed_source: z.enum(['default', 'uploaded', 'generated']),
+  // Scene Authoring prompt that produced the current world, if any.
+  prompt: z.string().nullable().default(null),
+  engine_model: z.string(),
+  engine_backend: EngineBackendSchema.nullable().default(null),
+  engine_mode: z.enum(['standalone', 'server']),
+  // Empty in standalone mode — the local server URL is an implementation
+  // detail that changes with port selection.
+  server_url: z.string().default(''),
+  // Biome ships the server components with the app, so the app version
+  // pins the engine version too.
+  app_version: z.string(),
+  created_at_ms: z.number(),
+  updated_at_ms: z.number()
+})
+
+export type SessionRecord = z.infer<typeof sessionRecordSchema>
+
+/** Renderer-supplied fields for `save-session`. The main process fills in
+ *  `id` (unless updating an existing record), `app_version` and timestamps. */
+export type SessionDraft = Omit<SessionRecord, 'id' | 'app_version' | 'created_at_ms' | 'updated_at_ms'> & {
+  id?: string
+}
+
+/** Bumped when the `.biomeworld` world file layout changes incompatibly. */
+export const WORLD_FILE_FORMAT_VERSION = 1
+
+/** `manifest.json` of a `.biomeworld` world file — a saved session packaged
+ *  for sharing. The seed image sits next to it in the archive under `seed/`.
+ *  Machine-local fields (id, timestamps, server URL) are left out. */
+export const worldFileManifestSchema = z.object({
+  format_version: z.literal(WORLD_FILE_FORMAT_VERSION),
+  session: sessionRecordSchema.omit({ id: true, server_url: true, created_at_ms: true, updated_at_ms: true })
+})
+
+export type WorldFileManifest = z.infer<typeof worldFileManifestSchema>
+
+export const sessionStoreSchema = z.object({
+  sessions: z.array(sessionRecordSchema).default([])
+})
@@ -5,6 +5,7 @@ import type { AllPaths } from '../utils/settingsClassifier'
 
 export const ENGINE_MODES = { STANDALONE: 'standalone', SERVER: 'server' } as const
 export const LOCALE_OPTIONS = ['system', ...SUPPORTED_LOCALES] as const
+export const UPDATE_CHANNEL_OPTIONS = ['stable', 'beta'] as const
 
 // `EngineBackend` / `Quant` are the canonical wire enums defined in
 // `server.protocol`; the codegen ships them as Zod schemas, and we
@@ -17,6 +18,7 @@ export type { EngineBackend }
 export type QuantOption = Quant
 
 export type AppLocale = (typeof LOCALE_OPTIONS)[number]
+export type UpdateChannel = (typeof UPDATE_CHANNEL_OPTIONS)[number]
 
 export const DEFAULT_ENGINE_MODEL = 'Overworld/Waypoint-1.5-1B'
 
@@ -50,7 +52,8 @@ export const DEFAULT_KEYBINDINGS = {
   secondaryFire: 'MouseRight',
   pauseMenu: 'Escape',
   resetScene: 'KeyU',
-  sceneEdit: 'KeyQ'
+  sceneEdit: 'KeyQ',
+  screenshot: 'F2'
 } as const
 
 export type ControlBindKey = keyof typeof DEFAULT_KEYBINDINGS
@@ -65,13 +68,55 @@ export const DEFAULT_AUDIO = {
   music_volume: 0.3
 } as const
 
+/** A named server URL the user can switch to from the engine tab (e.g. a
+ *  local GPU box and a rented cloud one). `server_url` remains the active
+ *  endpoint; picking a profile just copies its URL in. */
+export const serverProfileSchema = z.object({
+  name: z.string(),
+  url: z.string()
+})
+
+export type ServerProfile = z.infer<typeof serverProfileSchema>
+
 // Adding a field? Classify it in `SETTING_CLASSES` below if the server
 // cares — anything unlisted silently defaults to `'none'` (no restart on
 // change), which is the wrong default for any field that touches the
 // engine.
 export const settingsSchema = z.object({
   locale: z.enum(LOCALE_OPTIONS).default('system'),
+  // Which GitHub releases the update check considers: `stable` only sees
+  // full releases, `beta` also offers prereleases to testers.
+  update_channel: z.enum(UPDATE_CHANNEL_OPTIONS).default('stable'),
+  // Closing the window hides it to the tray instead of quitting, so the
+  // standalone engine stays warm in the background.
+  minimize_to_tray: z.boolean().default(false),
+  // Native notifications when engine setup finishes, a world fails to
+  // load, or the engine crashes while Biome isn't focused.
+  notifications: z
+    .object({
+      enabled: z.boolean().default(true)
+    })
+    .default({ enabled: true }),
+  // Periodic housekeeping (`electron/ipc/maintenance.ts`), one flag per
+  // job. The uv package cache is pruned once it grows past
+  // `uv_cache_budget_gb`.
+  maintenance: z
+    .object({
+      prune_uv_cache: z.boolean().default(true),
+      uv_cache_budget_gb: z.number().positive().default(20),
+      prune_crash_reports: z.boolean().default(true),
+      clean_stale_downloads: z.boolean().default(true),
+      check_for_updates: z.boolean().default(true)
+    })
+    .default({
+      prune_uv_cache: true,
+      uv_cache_budget_gb: 20,
+      prune_crash_reports: true,
+      clean_stale_downloads: true,
+      check_for_updates: true
+    }),
   server_url: z.string().default(''),
+  server_profiles: z.array(serverProfileSchema).default([]),
   engine_mode: z.enum(['standalone', 'server']).default('standalone'),
   engine_model: z.string().default(DEFAULT_ENGINE_MODEL),
   // User-added custom HF repo ids that the picker should surface
@@ -85,8 +130,47 @@ export const settingsSchema = z.object({
   engine_quant: QuantSchema.default('none'),
   cap_inference_fps: z.boolean().default(true),
   offline_mode: z.boolean().default(false),
+  // For networks where GitHub / PyPI / HuggingFace are blocked or slow.
+  // Empty strings mean "use the default". `proxy` applies to the app's own
+  // downloads and to every uv / python subprocess; the mirrors replace the
+  // uv release host, the default package index, the managed-Python
+  // download host, and the HF hub endpoint respectively. Values are
+  // trimmed on parse, so every consumer sees the same string.
+  network: z
+    .object({
+      proxy: z.string().trim().default(''),
+      uv_mirror: z.string().trim().default(''),
+      pypi_mirror: z.string().trim().default(''),
+      python_mirror: z.string().trim().default(''),
+      hf_mirror: z.string().trim().default('')
+    })
+    .default({ proxy: '', uv_mirror: '', pypi_mirror: '', python_mirror: '', hf_mirror: '' }),
+  // Standalone server launch tweaks for multi-GPU machines and debugging.
+  // `gpu_device` becomes `CUDA_VISIBLE_DEVICES`, `extra_args` are appended
+  // to the server command line, and `extra_env` is merged over Biome's own
+  // environment. Precision and resolution stay session settings
+  // (`engine_quant`) since the server applies them per model load.
+  engine_launch: z
+    .object({
+      gpu_device: z.string().default(''),
+      extra_args: z.array(z.string()).default([]),
+      extra_env: z.record(z.string(), z.string()).default({})
+    })
+    .default({ gpu_device: '', extra_args: [], extra_env: {} }),
+  // Restart the standalone engine when it exits on its own, backing off
+  // between attempts (`EngineLifecycleContext`). Off leaves a crashed
+  // engine in the failed state until the user restarts or fixes it.
+  engine_auto_restart: z.boolean().default(true),
   mouse_sensitivity: sensitivitySchema,
   gamepad_sensitivity: sensitivitySchema,
+  // Aids for players who can't hold keys down. `toggle_movement` latches
+  // the movement, sprint and crouch bindings: one press starts the action,
+  // the next press stops it.
+  accessibility: z
+    .object({
+      toggle_movement: z.boolean().default(false)
+    })
+    .default({ toggle_movement: false }),
   // Ordered list of scene filenames as shown in the pause-menu grid. Users
   // drag to reorder; whatever's at the top is most prominent.
   scene_order: z.array(z.string()).default(DEFAULT_SCENE_ORDER),
@@ -105,7 +189,8 @@ export const settingsSchema = z.object({
       secondaryFire: z.string().default(DEFAULT_KEYBINDINGS.secondaryFire),
       pauseMenu: z.string().default(DEFAULT_KEYBINDINGS.pauseMenu),
       resetScene: z.string().default(DEFAULT_KEYBINDINGS.resetScene),
-      sceneEdit: z.string().default(DEFAULT_KEYBINDINGS.sceneEdit)
+      sceneEdit: z.string().default(DEFAULT_KEYBINDINGS.sceneEdit),
+      screenshot: z.string().default(DEFAULT_KEYBINDINGS.screenshot)
     })
     .default(DEFAULT_KEYBINDINGS),
   audio: z
@@ -130,6 +215,25 @@ export const settingsSchema = z.object({
       frame_timeline: false,
       action_logging: false
     }),
+  // Window behaviour while streaming. `session_fullscreen` enters session
+  // mode (fullscreen, no display sleep) for the length of each session, on
+  // `preferred_monitor` (an Electron display id) or, when null or no longer
+  // connected, whichever display the window is on. `idle_pause_minutes`
+  // pauses the session after that long without input (0 = never).
+  display: z
+    .object({
+      session_fullscreen: z.boolean().default(false),
+      preferred_monitor: z.number().int().nullable().default(null),
+      idle_pause_minutes: z.number().int().min(0).max(120).default(0)
+    })
+    .default({ session_fullscreen: false, preferred_monitor: null, idle_pause_minutes: 0 }),
+  // The screenshot keybind always saves a PNG to `screenshots/` under the
+  // user data dir; `copy_to_clipboard` also puts it on the clipboard.
+  screenshots: z
+    .object({
+      copy_to_clipboard: z.boolean().default(false)
+    })
+    .default({ copy_to_clipboard: false }),
   // Video recording (standalone mode only). output_dir is user-configurable;
   // the empty-string default means "use the OS video directory + /Biome",
   // resolved at the Electron layer via resolve-video-dir.
@@ -163,6 +267,8 @@ export const SETTING_CLASSES: Partial<Record<SettingPath, SettingClass>> = {
   // Process: env vars / URL only apply at process spawn time.
   engine_mode: 'process',
   offline_mode: 'process',
+  network: 'process',
+  engine_launch: 'process',
   server_url: 'process',
 
   // Session: model / engine / world identity.
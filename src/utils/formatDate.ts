@@ -0,0 +1,6 @@
+/** Medium date + short time in the UI language. `goose` isn't a real
+ *  locale tag, so it falls back to the system default. */
+export const formatDate = (ms: number, locale: string): string => {
+  const resolved = locale === 'goose' ? undefined : locale
+  return new Intl.DateTimeFormat(resolved, { dateStyle: 'medium', timeStyle: 'short' }).format(new Date(ms))
+}